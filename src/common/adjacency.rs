@@ -0,0 +1,71 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+use crate::common::Data;
+
+/// Build the adjacency graph stored in `Data::adjacency_indices` and `Data::adjacency_types`.
+///
+/// Every object gets an entry, listing its `(neighbor guid, adjacency type)` pairs in
+/// the order they were added. Links are kept as stored, so a link only appears in both
+/// directions if both objects record it.
+///
+/// # Example
+///
+/// ```
+/// use openmodel::common::{build_adjacency_graph, Data};
+/// let a = Data::with_name("a");
+/// let mut b = Data::with_name("b");
+/// b.add_adjacency(a.guid(), "joint");
+/// let graph = build_adjacency_graph(&[&a, &b]);
+/// assert!(graph[&a.guid()].is_empty());
+/// assert_eq!(graph[&b.guid()], vec![(a.guid(), "joint".to_string())]);
+/// ```
+pub fn build_adjacency_graph(objects: &[&Data]) -> HashMap<Uuid, Vec<(Uuid, String)>> {
+    objects
+        .iter()
+        .map(|data| {
+            let links = data
+                .adjacency_indices()
+                .iter()
+                .zip(data.adjacency_types())
+                .map(|(guid, kind)| (*guid, kind.clone()))
+                .collect();
+            (data.guid(), links)
+        })
+        .collect()
+}
+
+/// Collect all objects below `parent` by following `Data::parent` links.
+///
+/// Children come before grandchildren (breadth first); siblings keep the order of
+/// `objects`. The parent itself is not included and parent cycles are followed once.
+///
+/// # Example
+///
+/// ```
+/// use openmodel::common::{descendants_of, Data};
+/// let root = Data::with_name("root");
+/// let mut child = Data::with_name("child");
+/// child.set_parent(Some(root.guid()));
+/// assert_eq!(descendants_of(&[&root, &child], root.guid()), vec![child.guid()]);
+/// ```
+pub fn descendants_of(objects: &[&Data], parent: Uuid) -> Vec<Uuid> {
+    let mut children: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for data in objects {
+        if let Some(p) = data.parent() {
+            children.entry(p).or_default().push(data.guid());
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut visited: HashSet<Uuid> = HashSet::from([parent]);
+    let mut queue: VecDeque<Uuid> = VecDeque::from([parent]);
+    while let Some(current) = queue.pop_front() {
+        for child in children.get(&current).into_iter().flatten() {
+            if visited.insert(*child) {
+                out.push(*child);
+                queue.push_back(*child);
+            }
+        }
+    }
+    out
+}
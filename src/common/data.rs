@@ -3,6 +3,7 @@ use serde_json::Value;
 use uuid::Uuid;
 use std::fmt;
 use crate::geometry::Color;
+use crate::primitives::Xform;
 
 /// Enhanced Data struct that combines metadata and serialization capabilities
 /// Similar to COMPAS Data class for all serializable geometric objects
@@ -294,6 +295,16 @@ impl Data {
     pub fn reset_transformation(&mut self) {
         self.transformation = Self::identity_matrix();
     }
+
+    /// Get the transformation matrix as an `Xform`
+    pub fn xform(&self) -> Xform {
+        Xform { m: self.transformation }
+    }
+
+    /// Set the transformation matrix from an `Xform`
+    pub fn set_xform(&mut self, xform: &Xform) {
+        self.transformation = xform.m;
+    }
     
     /// Get the color as an RGB array [r, g, b]
     pub fn get_color(&self) -> [u8; 3] {
@@ -452,10 +463,7 @@ impl fmt::Display for Data {
             self.guid,
             self.parent,
             self.adjacency_indices.len(),
-            !self.transformation.iter().enumerate().all(|(i, &val)| {
-                (i % 5 == 0 && (val - 1.0).abs() < f32::EPSILON) || // Diagonal elements are 1.0
-                (i % 5 != 0 && val.abs() < f32::EPSILON) // Non-diagonal elements are 0.0
-            })
+            !Xform::from_matrix(self.transformation).is_identity(f32::EPSILON)
         )
     }
 }
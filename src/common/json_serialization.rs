@@ -1,5 +1,6 @@
 use serde_json::Value;
 use std::fmt;
+use crate::Error;
 
 /// Smart JSON data wrapper that allows direct access like Python COMPAS
 /// No casting or unwrapping needed!
@@ -130,7 +131,7 @@ impl JsonHandler {
     }
     
     /// Save to JSON file similar to COMPAS json_dump
-    pub fn dump(&self, json_data: Value, path: &str, pretty: bool) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn dump(&self, json_data: Value, path: &str, pretty: bool) -> Result<(), Error> {
         let json_str = self.dumps(json_data, pretty)?;
         std::fs::write(path, json_str)?;
         Ok(())
@@ -142,27 +143,27 @@ impl JsonHandler {
     }
     
     /// Load JSON from file similar to COMPAS json_load
-    pub fn load(&self, path: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    pub fn load(&self, path: &str) -> Result<Value, Error> {
         let json_str = std::fs::read_to_string(path)?;
         let json_data = self.loads(&json_str)?;
         Ok(json_data)
     }
     
     /// Ultra-simple: Save geometry object to file in one line
-    pub fn save_object<T: HasJsonData>(&self, obj: &T, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn save_object<T: HasJsonData>(&self, obj: &T, path: &str) -> Result<(), Error> {
         let json_data = obj.to_json_data(false);
         self.dump(json_data, path, true)
     }
     
     /// Ultra-simple: Save collection of geometry objects to file in one line
-    pub fn save_collection(&self, objects: Vec<Value>, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn save_collection(&self, objects: Vec<Value>, path: &str) -> Result<(), Error> {
         let json_str = serialize_collection(objects, true)?;
         std::fs::write(path, json_str)?;
         Ok(())
     }
     
     /// Ultra-simple: Load geometry data from file in one line
-    pub fn load_geometry(&self, path: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    pub fn load_geometry(&self, path: &str) -> Result<Value, Error> {
         self.load(path)
     }
 }
@@ -249,102 +250,66 @@ impl JsonSerializable for Vec<Value> {
     }
 }
 
-/// PERFECTLY SIMPLE: Save ANY geometry object or collection (COMPAS-style json_dump)
-/// 
-/// No error handling needed - just works!
+/// Save ANY geometry object or collection (COMPAS-style json_dump)
 /// 
 /// Works for:
 /// - Single objects: `json_dump(&point, "file.json")`
 /// - Collections: `json_dump(&vec_of_points, "file.json")`
 /// - Pre-serialized: `json_dump(&vec_of_json_values, "file.json")`
 /// 
+/// # Errors
+/// `Error::Io` if the file can't be written, `Error::Parse` if serialization fails.
+/// 
 /// # Example
 /// ```
-/// use openmodel::geometry::Point;
+/// use openmodel::geometry::Vector;
 /// use openmodel::common::json_dump;
 /// 
-/// // Single object - no .unwrap() needed!
-/// let point1 = Point::new(1.0, 2.0, 3.0);
-/// json_dump(&point1, "point.json");
+/// // Single object
+/// let point1 = Vector::new(1.0, 2.0, 3.0);
+/// json_dump(&point1, "point.json").unwrap();
 /// 
-/// // Collection of objects - no .unwrap() needed!
-/// let point2 = Point::new(4.0, 5.0, 6.0);
-/// let point3 = Point::new(7.0, 8.0, 9.0);
+/// // Collection of objects
+/// let point2 = Vector::new(4.0, 5.0, 6.0);
+/// let point3 = Vector::new(7.0, 8.0, 9.0);
 /// let points = vec![point1, point2, point3];
-/// json_dump(&points, "collection.json");
+/// json_dump(&points, "collection.json").unwrap();
+/// # let _ = std::fs::remove_file("point.json");
+/// # let _ = std::fs::remove_file("collection.json");
 /// ```
-pub fn json_dump<T: JsonSerializable>(obj: &T, path: &str) {
-    let json_value = obj.to_json_value();
-    let json_string = match serde_json::to_string_pretty(&json_value) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("Warning: JSON serialization failed: {}. Using minimal format.", e);
-            serde_json::to_string(&json_value).unwrap_or_else(|_| "{}".to_string())
-        }
-    };
-    
-    if let Err(e) = std::fs::write(path, json_string) {
-        eprintln!("Warning: Failed to write to {}: {}. Data not saved.", path, e);
-    }
+pub fn json_dump<T: JsonSerializable>(obj: &T, path: &str) -> Result<(), Error> {
+    let json_string = serde_json::to_string_pretty(&obj.to_json_value())?;
+    std::fs::write(path, json_string)?;
+    Ok(())
 }
 
-/// PERFECTLY SIMPLE: Load geometry directly as Rust types (COMPAS-style json_load)
+/// Load geometry directly as Rust types (COMPAS-style json_load)
 /// 
-/// No error handling, no casting, no unwrapping - just works!
 /// Returns the actual geometry type directly!
 /// 
-/// # Example  
-/// ```no_run
-/// use openmodel::geometry::Point;
-/// use openmodel::common::json_load;
-/// use std::fs::File;
-/// use std::io::Write;
-/// use serde_json::json;
+/// # Errors
+/// `Error::Io` if the file can't be read, `Error::Parse` if it isn't valid JSON or
+/// doesn't describe a `T`.
 /// 
-/// // For doctest purposes, create a test file
-/// let test_data = json!({
-///     "type": "Point",
-///     "x": 1.0,
-///     "y": 2.0,
-///     "z": 3.0,
-///     "data": {
-///         "name": "TestPoint",
-///         "guid": "00000000-0000-0000-0000-000000000000"
-///     }
-/// });
+/// # Example  
+/// ```
+/// use openmodel::geometry::Vector;
+/// use openmodel::common::{json_dump, json_load};
 /// 
-/// // Write to a temporary file
-/// let temp_path = "temp_test_point.json";
-/// let mut file = File::create(temp_path).unwrap();
-/// file.write_all(test_data.to_string().as_bytes()).unwrap();
+/// let temp_path = "temp_test_vector.json";
+/// json_dump(&Vector::new(1.0, 2.0, 3.0), temp_path).unwrap();
 /// 
-/// // Now load the point
-/// let point: Point = json_load(temp_path);
-/// println!("Point at ({}, {}, {})", point.x, point.y, point.z);
+/// let v: Vector = json_load(temp_path).unwrap();
+/// assert_eq!((v.x, v.y, v.z), (1.0, 2.0, 3.0));
 /// 
 /// // Clean up
 /// std::fs::remove_file(temp_path).unwrap();
 /// ```
-pub fn json_load<T: FromJsonData>(path: &str) -> T {
-    let json_str = match std::fs::read_to_string(path) {
-        Ok(content) => content,
-        Err(e) => {
-            eprintln!("Warning: Failed to read {}: {}. Using default.", path, e);
-            return T::from_json_data(&Value::Object(serde_json::Map::new()))
-                .unwrap_or_else(|| panic!("Failed to create default object"));
-        }
-    };
-    
-    let json_data: Value = match serde_json::from_str(&json_str) {
-        Ok(data) => data,
-        Err(e) => {
-            eprintln!("Warning: Failed to parse JSON from {}: {}. Using default.", path, e);
-            Value::Object(serde_json::Map::new())
-        }
-    };
-    
+pub fn json_load<T: FromJsonData>(path: &str) -> Result<T, Error> {
+    let json_str = std::fs::read_to_string(path)?;
+    let json_data: Value = serde_json::from_str(&json_str)?;
     T::from_json_data(&json_data)
-        .unwrap_or_else(|| panic!("Failed to deserialize {} from JSON", std::any::type_name::<T>()))
+        .ok_or_else(|| Error::Parse(format!("failed to deserialize {} from {}", std::any::type_name::<T>(), path)))
 }
 
 #[cfg(test)]
@@ -1,6 +1,10 @@
+pub mod adjacency;
 pub mod data;
 pub mod json_serialization;
+pub mod registry;
 
 // Re-export commonly used types and functions
+pub use adjacency::{build_adjacency_graph, descendants_of};
 pub use data::Data;
+pub use registry::GeometryRegistry;
 pub use json_serialization::{JsonSerializable, JsonData, json_dump, json_load, HasJsonData, FromJsonData};
\ No newline at end of file
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::common::Data;
+
+/// Index of scene objects by guid and name.
+///
+/// The registry stores no geometry. `register` returns a handle, the registration
+/// order index, which callers use to find the object in their own storage.
+/// Guids are unique; names may be shared by several objects.
+#[derive(Debug, Clone, Default)]
+pub struct GeometryRegistry {
+    /// Registered (guid, name) pairs, indexed by handle
+    entries: Vec<(Uuid, String)>,
+    /// Handle of each guid
+    guids: HashMap<Uuid, usize>,
+    /// Handles of each name, in registration order
+    names: HashMap<String, Vec<usize>>,
+}
+
+impl GeometryRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an object and returns its handle.
+    ///
+    /// Registering a guid again keeps its handle and updates its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `guid` - The unique identifier of the object.
+    /// * `name` - The name of the object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::common::{Data, GeometryRegistry};
+    /// let data = Data::with_name("beam");
+    /// let mut registry = GeometryRegistry::new();
+    /// let handle = registry.register(data.guid(), data.name());
+    /// assert_eq!(registry.by_guid(&data.guid()), Some(handle));
+    /// assert_eq!(registry.by_name("beam"), &[handle]);
+    /// ```
+    pub fn register(&mut self, guid: Uuid, name: &str) -> usize {
+        if let Some(&handle) = self.guids.get(&guid) {
+            let old = std::mem::replace(&mut self.entries[handle].1, name.to_string());
+            if old != name {
+                if let Some(handles) = self.names.get_mut(&old) {
+                    handles.retain(|h| *h != handle);
+                    if handles.is_empty() {
+                        self.names.remove(&old);
+                    }
+                }
+                self.names.entry(name.to_string()).or_default().push(handle);
+            }
+            return handle;
+        }
+        let handle = self.entries.len();
+        self.entries.push((guid, name.to_string()));
+        self.guids.insert(guid, handle);
+        self.names.entry(name.to_string()).or_default().push(handle);
+        handle
+    }
+
+    /// Registers an object by its `Data` and returns its handle.
+    pub fn register_data(&mut self, data: &Data) -> usize {
+        self.register(data.guid(), data.name())
+    }
+
+    /// Handle of the object with the given guid.
+    pub fn by_guid(&self, guid: &Uuid) -> Option<usize> {
+        self.guids.get(guid).copied()
+    }
+
+    /// Handles of all objects with the given name, in registration order.
+    pub fn by_name(&self, name: &str) -> &[usize] {
+        self.names.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Guid and name registered under a handle.
+    pub fn get(&self, handle: usize) -> Option<(Uuid, &str)> {
+        self.entries.get(handle).map(|(guid, name)| (*guid, name.as_str()))
+    }
+
+    /// Number of registered objects.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no objects are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+/// Errors returned by the fallible parts of the crate: file IO, parsing and
+/// geometry operations that cannot produce a result.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading or writing a file failed.
+    Io(std::io::Error),
+    /// Input text or JSON could not be parsed into the requested type.
+    Parse(String),
+    /// The geometry is not valid for the requested operation.
+    InvalidGeometry(String),
+    /// An index was outside the valid range `0..len`.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// A matrix or system could not be inverted.
+    Singular,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "IO error: {}", e),
+            Error::Parse(msg) => write!(f, "parse error: {}", msg),
+            Error::InvalidGeometry(msg) => write!(f, "invalid geometry: {}", msg),
+            Error::IndexOutOfBounds { index, len } => write!(f, "index {} out of bounds for length {}", index, len),
+            Error::Singular => write!(f, "singular matrix"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parse(e.to_string())
+    }
+}
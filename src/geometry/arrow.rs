@@ -2,11 +2,12 @@ use crate::geometry::{Point, Mesh};
 use crate::geometry::Vector;
 use crate::common::Data;
 use crate::common::{JsonSerializable, FromJsonData};
-use crate::primitives::Xform;
+use crate::primitives::{Transformable, Xform};
 
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 // use std::f64::consts::PI;  // Not needed
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,11 +26,25 @@ pub struct Arrow {
     pub z1: f32,
     /// The data associated with the Arrow (includes color and thickness).
     pub data: Data,
+    /// Number of sides of the pipe cross-section used for the mesh.
+    #[serde(default = "default_pipe_sides")]
+    pub pipe_sides: usize,
+    /// Whether the mesh ends with a cone head.
+    #[serde(default = "default_head")]
+    pub head: bool,
     /// Mesh for visualization (pipe)
     #[serde(skip)]
     pub mesh: Option<Mesh>,
 }
 
+fn default_pipe_sides() -> usize {
+    8
+}
+
+fn default_head() -> bool {
+    true
+}
+
 impl Arrow{
     /// Creates a new `Arrow` with default `Data`.
     ///
@@ -64,6 +79,8 @@ impl Arrow{
             y1,
             z1,
             data: Data::with_name("Arrow"),
+            pipe_sides: default_pipe_sides(),
+            head: default_head(),
             mesh: None,
         }
     }
@@ -101,6 +118,8 @@ impl Arrow{
             y1,
             z1,
             data: Data::with_name(&name),
+            pipe_sides: default_pipe_sides(),
+            head: default_head(),
             mesh: None,
         }
     }
@@ -135,6 +154,8 @@ impl Arrow{
             y1:p1.y,
             z1:p1.z,
             data: Data::with_name("Arrow"),
+            pipe_sides: default_pipe_sides(),
+            head: default_head(),
             mesh: None,
         }
     }
@@ -167,9 +188,12 @@ impl Arrow{
         let start = Point::new(self.x0, self.y0, self.z0);
         let end = Point::new(self.x1, self.y1, self.z1);
         
-        // Use fixed 8 sides for the pipe cross-section
-        // Generate the mesh
-        self.mesh = Some(Mesh::create_pipe(start, end, thickness));
+        // Generate the mesh with the configured cross-section
+        self.mesh = Some(if self.head {
+            Mesh::create_arrow(start, end, thickness, self.pipe_sides)
+        } else {
+            Mesh::create_pipe_with_sides(start, end, thickness, self.pipe_sides)
+        });
         
         // If the Arrow has a color, apply it to the mesh
         if self.data.has_color() {
@@ -181,6 +205,31 @@ impl Arrow{
         self
     }
 
+    /// Sets the number of sides of the pipe cross-section (at least 3).
+    /// The cached mesh is cleared and rebuilt on the next `get_mesh` call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Arrow;
+    /// let mut arrow = Arrow::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// arrow.set_pipe_sides(16);
+    /// assert_eq!(arrow.pipe_sides, 16);
+    /// ```
+    pub fn set_pipe_sides(&mut self, sides: usize) -> &mut Self {
+        self.pipe_sides = sides.max(3);
+        self.mesh = None;
+        self
+    }
+
+    /// Enables or disables the cone head of the mesh.
+    /// The cached mesh is cleared and rebuilt on the next `get_mesh` call.
+    pub fn set_head(&mut self, head: bool) -> &mut Self {
+        self.head = head;
+        self.mesh = None;
+        self
+    }
+
     /// Gets the mesh representation of this Arrow as a pipe.
     /// If the mesh doesn't exist, creates it first.
     /// 
@@ -207,17 +256,25 @@ impl Arrow{
     /// Returns a transform that maps the canonical unit pipe (aligned to +Z, length=1, radius=0.5,
     /// centered at the origin with z in [-0.5, +0.5]) onto this Arrow segment.
     /// Uses the JSON transformation matrix directly if available, otherwise falls back to coordinate-based calculation.
+    /// Returns None for a zero-length arrow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Arrow, Point};
+    /// let arrow = Arrow::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+    /// let xform = arrow.to_pipe_transform().unwrap();
+    /// let tip = xform.transform_point(&Point::new(0.0, 0.0, 0.5));
+    /// assert!((tip.x - 1.0).abs() < 1e-5 && (tip.y - 1.0).abs() < 1e-5 && (tip.z - 1.0).abs() < 1e-5);
+    /// ```
     pub fn to_pipe_transform(&self) -> Option<Xform> {
         // Check if we have a non-identity transformation matrix in the JSON data
         let json_transform = self.data.transformation();
-        let is_identity = json_transform.iter().enumerate().all(|(i, &val)| {
-            (i % 5 == 0 && (val - 1.0).abs() < f32::EPSILON) || // Diagonal elements are 1.0
-            (i % 5 != 0 && val.abs() < f32::EPSILON) // Non-diagonal elements are 0.0
-        });
+        let is_identity = Xform::from_matrix(*json_transform).is_identity(f32::EPSILON);
 
         if !is_identity {
             // Use the JSON transformation matrix directly - much more efficient!
-            return Some(Xform { m: *json_transform });
+            return Some(Xform::from_matrix(*json_transform));
         }
 
         // Fallback: rebuild from coordinates (for backward compatibility)
@@ -248,11 +305,7 @@ impl Arrow{
         };
 
         // Midpoint translation
-        let midpoint = Point::new(
-            (p0.x + p1.x) * 0.5,
-            (p0.y + p1.y) * 0.5,
-            (p0.z + p1.z) * 0.5,
-        );
+        let midpoint = p0.midpoint(&p1);
         let translation = Xform::translation(midpoint.x, midpoint.y, midpoint.z);
 
         // Non-uniform scale: XY = 1.0 (unit pipe geometry radius is unused for thickness), Z = length
@@ -281,11 +334,48 @@ impl Default for Arrow{
             y1: 0.0,
             z1: 1.0,
             data: Data::with_name("Arrow"),
+            pipe_sides: default_pipe_sides(),
+            head: default_head(),
             mesh: None,
         }
     }
 }
 
+impl PartialEq for Arrow {
+    /// Checks if two arrows have the same start and end points. `data` and the mesh
+    /// settings are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Arrow;
+    /// let a1 = Arrow::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// let a2 = Arrow::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(a1, a2);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.x0 == other.x0
+            && self.y0 == other.y0
+            && self.z0 == other.z0
+            && self.x1 == other.x1
+            && self.y1 == other.y1
+            && self.z1 == other.z1
+    }
+}
+
+/// Arrows are only `Eq` as long as no coordinate is NaN.
+impl Eq for Arrow {}
+
+impl Hash for Arrow {
+    /// Hashes the f32 bit patterns of the end points (-0.0 and 0.0 are treated as equal).
+    /// Arrows are directed, so an arrow and its reverse hash differently.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for v in [self.x0, self.y0, self.z0, self.x1, self.y1, self.z1] {
+            (v + 0.0).to_bits().hash(state);
+        }
+    }
+}
+
 impl Add<&Vector> for Arrow {
     type Output = Arrow;
 
@@ -318,6 +408,8 @@ impl Add<&Vector> for Arrow {
             y1: self.y1 + other.y,
             z1: self.z1 + other.z,
             data: Data::with_name("Arrow"),
+            pipe_sides: self.pipe_sides,
+            head: self.head,
             mesh: None,
         }
     }
@@ -586,6 +678,8 @@ impl Sub<&Vector> for Arrow {
             y1: self.y1 - vector.y,
             z1: self.z1 - vector.z,
             data: Data::with_name("Arrow"),
+            pipe_sides: self.pipe_sides,
+            head: self.head,
             mesh: None,
         }
     }
@@ -649,6 +743,22 @@ impl From<Arrow> for Vector {
     }
 }
 
+// Implement Transformable for Arrow
+impl Transformable for Arrow {
+    /// Transforms both end points and drops the cached pipe mesh.
+    fn transform(&mut self, xform: &Xform) {
+        let p0 = xform.transform_point(&Point::new(self.x0, self.y0, self.z0));
+        let p1 = xform.transform_point(&Point::new(self.x1, self.y1, self.z1));
+        self.x0 = p0.x;
+        self.y0 = p0.y;
+        self.z0 = p0.z;
+        self.x1 = p1.x;
+        self.y1 = p1.y;
+        self.z1 = p1.z;
+        self.mesh = None;
+    }
+}
+
 impl fmt::Display for Arrow{
     /// Log Arrow.
     /// # Example
@@ -672,7 +782,9 @@ impl JsonSerializable for Arrow {
             "z0": self.z0,
             "x1": self.x1,
             "y1": self.y1,
-            "z1": self.z1
+            "z1": self.z1,
+            "pipe_sides": self.pipe_sides,
+            "head": self.head
         });
         self.data.to_json_data("openmodel.geometry/Arrow", geometric_data, false)
     }
@@ -0,0 +1,166 @@
+use crate::geometry::{Plane, Point, Vector};
+use crate::common::{JsonSerializable, FromJsonData};
+use crate::common::Data;
+use crate::primitives::{Transformable, Xform};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A right-handed orthonormal coordinate system: the rigid-orientation counterpart to `Plane`.
+///
+/// Unlike `Plane`, a `Frame` has no plane equation; its axes are always kept unit length
+/// and mutually perpendicular.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Frame {
+    /// The origin point.
+    pub origin: Point,
+    /// The x-axis.
+    pub xaxis: Vector,
+    /// The y-axis.
+    pub yaxis: Vector,
+    /// The z-axis.
+    pub zaxis: Vector,
+    /// Associated data - guid and name.
+    pub data: Data,
+}
+
+impl Frame {
+    /// Creates a new `Frame` from an origin and two axis directions.
+    ///
+    /// The x-axis keeps its direction, the y-axis is made perpendicular to it within
+    /// the plane of both vectors and the z-axis completes the right-handed system.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin point.
+    /// * `xaxis` - The x-axis direction.
+    /// * `yaxis` - A direction in the xy-plane, not parallel to `xaxis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Frame, Point, Vector};
+    /// let frame = Frame::new(Point::new(1.0, 2.0, 3.0), Vector::new(2.0, 0.0, 0.0), Vector::new(1.0, 1.0, 0.0));
+    /// assert_eq!(frame.xaxis.x, 1.0);
+    /// assert_eq!(frame.yaxis.y, 1.0);
+    /// assert_eq!(frame.zaxis.z, 1.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axes are zero length or parallel.
+    pub fn new(origin: Point, xaxis: Vector, yaxis: Vector) -> Self {
+        let mut x = xaxis;
+        let mut z = xaxis.cross(&yaxis);
+        if !x.unitize() || !z.unitize() {
+            panic!("Frame axes cannot be zero length or parallel");
+        }
+        Frame {
+            origin,
+            xaxis: x,
+            yaxis: z.cross(&x),
+            zaxis: z,
+            data: Data::with_name("Frame"),
+        }
+    }
+
+    /// Creates a `Frame` with the origin and axes of a `Plane`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Frame, Plane};
+    /// let frame = Frame::from_plane(&Plane::default());
+    /// assert_eq!(frame.zaxis.z, 1.0);
+    /// ```
+    pub fn from_plane(plane: &Plane) -> Self {
+        Frame::new(plane.origin, plane.xaxis, plane.yaxis)
+    }
+
+    /// Converts the frame to a `Plane` with the same origin and axes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Frame;
+    /// let plane = Frame::default().to_plane();
+    /// assert_eq!(plane.c, 1.0);
+    /// ```
+    pub fn to_plane(&self) -> Plane {
+        Plane::new(self.origin, self.xaxis, self.yaxis)
+    }
+}
+
+impl Default for Frame {
+    /// Creates the world XY frame at the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Frame;
+    /// let frame = Frame::default();
+    /// assert_eq!(frame.origin.x, 0.0);
+    /// assert_eq!(frame.xaxis.x, 1.0);
+    /// assert_eq!(frame.yaxis.y, 1.0);
+    /// assert_eq!(frame.zaxis.z, 1.0);
+    /// ```
+    fn default() -> Self {
+        Frame {
+            origin: Point::new(0.0, 0.0, 0.0),
+            xaxis: Vector::new(1.0, 0.0, 0.0),
+            yaxis: Vector::new(0.0, 1.0, 0.0),
+            zaxis: Vector::new(0.0, 0.0, 1.0),
+            data: Data::with_name("Frame"),
+        }
+    }
+}
+
+// Implement Transformable for Frame
+impl Transformable for Frame {
+    /// Transforms the origin and axes, then re-orthonormalizes the axes so the
+    /// frame stays rigid under scaling or shearing transforms.
+    fn transform(&mut self, xform: &Xform) {
+        self.origin = xform.transform_point(&self.origin);
+        let mut xaxis = xform.transform_vector(&self.xaxis);
+        let yaxis = xform.transform_vector(&self.yaxis);
+        let mut zaxis = xaxis.cross(&yaxis);
+        if !xaxis.unitize() || !zaxis.unitize() {
+            return;
+        }
+        self.xaxis = xaxis;
+        self.yaxis = zaxis.cross(&xaxis);
+        self.zaxis = zaxis;
+    }
+}
+
+impl fmt::Display for Frame {
+    /// Log frame.
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Frame;
+    /// let frame = Frame::default();
+    /// println!("{}", frame);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Frame {{ origin: {}, xaxis: {}, yaxis: {}, zaxis: {}, Data: {} }}", self.origin, self.xaxis, self.yaxis, self.zaxis, self.data)
+    }
+}
+
+// JSON serialization support
+impl JsonSerializable for Frame {
+    fn to_json_value(&self) -> serde_json::Value {
+        let geometric_data = serde_json::json!({
+            "origin": self.origin,
+            "xaxis": self.xaxis,
+            "yaxis": self.yaxis,
+            "zaxis": self.zaxis
+        });
+        self.data.to_json_data("openmodel.geometry/Frame", geometric_data, false)
+    }
+}
+
+impl FromJsonData for Frame {
+    fn from_json_data(data: &serde_json::Value) -> Option<Self> {
+        serde_json::from_value(data.clone()).ok()
+    }
+}
@@ -2,11 +2,12 @@ use crate::geometry::{Point, Mesh};
 use crate::geometry::Vector;
 use crate::common::Data;
 use crate::common::{JsonSerializable, FromJsonData};
-use crate::primitives::Xform;
+use crate::primitives::{Transformable, Xform};
 
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 // use std::f64::consts::PI;  // Not needed
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -155,6 +156,60 @@ impl Line{
             .sqrt()
     }
 
+    /// Computes the midpoint of the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Line, Point};
+    /// let line = Line::new(0.0, 0.0, 0.0, 2.0, 4.0, 6.0);
+    /// assert_eq!(line.midpoint(), Point::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn midpoint(&self) -> Point {
+        Point::new(self.x0, self.y0, self.z0).midpoint(&Point::new(self.x1, self.y1, self.z1))
+    }
+
+    /// Creates a parallel copy of the line, offset within the plane defined by `plane_normal`.
+    ///
+    /// The offset direction is `plane_normal × direction`, so a positive distance moves
+    /// the line to the left when looking down the normal. The copy keeps color and
+    /// thickness but gets a new guid.
+    ///
+    /// # Arguments
+    ///
+    /// * `distance` - The signed offset distance.
+    /// * `plane_normal` - Normal of the plane in which the line is offset.
+    ///
+    /// # Returns
+    /// The offset line, or an unchanged copy if the line has zero length or is
+    /// parallel to `plane_normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Line, Vector};
+    /// let line = Line::new(0.0, 0.0, 0.0, 2.0, 0.0, 0.0);
+    /// let offset = line.offset(1.0, &Vector::new(0.0, 0.0, 1.0));
+    /// assert_eq!(offset.y0, 1.0);
+    /// assert_eq!(offset.y1, 1.0);
+    /// ```
+    pub fn offset(&self, distance: f32, plane_normal: &Vector) -> Line {
+        let mut out = Line::from_points(
+            &Point::new(self.x0, self.y0, self.z0),
+            &Point::new(self.x1, self.y1, self.z1),
+        );
+        out.data = self.data.copy(false);
+
+        let dir = Vector::new(self.x1 - self.x0, self.y1 - self.y0, self.z1 - self.z0);
+        let mut side = plane_normal.cross(&dir);
+        if !side.unitize() {
+            return out;
+        }
+        let shift = side * distance;
+        out += &shift;
+        out
+    }
+
     /// Updates the mesh representation using thickness from data.
     /// 
     /// # Returns
@@ -207,17 +262,25 @@ impl Line{
     /// Returns a transform that maps the canonical unit pipe (aligned to +Z, length=1, radius=0.5,
     /// centered at the origin with z in [-0.5, +0.5]) onto this line segment.
     /// Uses the JSON transformation matrix directly if available, otherwise falls back to coordinate-based calculation.
+    /// Returns None for a zero-length line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Line, Point};
+    /// let line = Line::new(1.0, 0.0, 0.0, 1.0, 0.0, 4.0);
+    /// let xform = line.to_pipe_transform().unwrap();
+    /// let top = xform.transform_point(&Point::new(0.0, 0.0, 0.5));
+    /// assert!((top.z - 4.0).abs() < 1e-6);
+    /// ```
     pub fn to_pipe_transform(&self) -> Option<Xform> {
         // Check if we have a non-identity transformation matrix in the JSON data
         let json_transform = self.data.transformation();
-        let is_identity = json_transform.iter().enumerate().all(|(i, &val)| {
-            (i % 5 == 0 && (val - 1.0).abs() < f32::EPSILON) || // Diagonal elements are 1.0
-            (i % 5 != 0 && val.abs() < f32::EPSILON) // Non-diagonal elements are 0.0
-        });
+        let is_identity = Xform::from_matrix(*json_transform).is_identity(f32::EPSILON);
 
         if !is_identity {
             // Use the JSON transformation matrix directly - much more efficient!
-            return Some(Xform { m: *json_transform });
+            return Some(Xform::from_matrix(*json_transform));
         }
 
         // Fallback: rebuild from coordinates (for backward compatibility)
@@ -248,11 +311,7 @@ impl Line{
         };
 
         // Midpoint translation
-        let midpoint = Point::new(
-            (p0.x + p1.x) * 0.5,
-            (p0.y + p1.y) * 0.5,
-            (p0.z + p1.z) * 0.5,
-        );
+        let midpoint = p0.midpoint(&p1);
         let translation = Xform::translation(midpoint.x, midpoint.y, midpoint.z);
 
         // Non-uniform scale: XY = 1.0 (unit pipe geometry radius is unused for thickness), Z = length
@@ -261,6 +320,41 @@ impl Line{
         // Compose T * R * S (scale → rotate → translate)
         Some(translation * rotation * scale)
     }
+
+    /// Returns a copy of the line with its end points in canonical order, so that a line
+    /// and its reverse produce equal canonical lines. The start point is the
+    /// lexicographically smaller one, comparing x, then y, then z.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Line;
+    /// let line = Line::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    /// let reversed = Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    /// assert_ne!(line, reversed);
+    /// assert_eq!(line.canonical(), reversed.canonical());
+    /// ```
+    pub fn canonical(&self) -> Line {
+        let start = [self.x0 + 0.0, self.y0 + 0.0, self.z0 + 0.0];
+        let end = [self.x1 + 0.0, self.y1 + 0.0, self.z1 + 0.0];
+        let swap = start
+            .iter()
+            .zip(end.iter())
+            .map(|(a, b)| a.total_cmp(b))
+            .find(|ordering| ordering.is_ne())
+            .is_some_and(|ordering| ordering.is_gt());
+        let mut line = self.clone();
+        if swap {
+            line.x0 = self.x1;
+            line.y0 = self.y1;
+            line.z0 = self.z1;
+            line.x1 = self.x0;
+            line.y1 = self.y0;
+            line.z1 = self.z0;
+            line.mesh = None;
+        }
+        line
+    }
 }
 
 impl Default for Line{
@@ -286,6 +380,43 @@ impl Default for Line{
     }
 }
 
+impl PartialEq for Line {
+    /// Checks if two lines have the same start and end points. `data` is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Line;
+    /// let l1 = Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// let l2 = Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(l1, l2);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.x0 == other.x0
+            && self.y0 == other.y0
+            && self.z0 == other.z0
+            && self.x1 == other.x1
+            && self.y1 == other.y1
+            && self.z1 == other.z1
+    }
+}
+
+/// Lines are only `Eq` as long as no coordinate is NaN.
+impl Eq for Line {}
+
+impl Hash for Line {
+    /// Hashes the f32 bit patterns of the canonical end points, so a line and its reverse
+    /// hash the same. Coordinates are hashed exactly (-0.0 and 0.0 are treated as equal):
+    /// lines that differ by rounding noise hash differently, so snap coordinates first
+    /// when deduplicating computed geometry.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let line = self.canonical();
+        for v in [line.x0, line.y0, line.z0, line.x1, line.y1, line.z1] {
+            (v + 0.0).to_bits().hash(state);
+        }
+    }
+}
+
 impl Add<&Vector> for Line {
     type Output = Line;
 
@@ -649,6 +780,22 @@ impl From<Line> for Vector {
     }
 }
 
+// Implement Transformable for Line
+impl Transformable for Line {
+    /// Transforms both end points and drops the cached pipe mesh.
+    fn transform(&mut self, xform: &Xform) {
+        let p0 = xform.transform_point(&Point::new(self.x0, self.y0, self.z0));
+        let p1 = xform.transform_point(&Point::new(self.x1, self.y1, self.z1));
+        self.x0 = p0.x;
+        self.y0 = p0.y;
+        self.z0 = p0.z;
+        self.x1 = p1.x;
+        self.y1 = p1.y;
+        self.z1 = p1.z;
+        self.mesh = None;
+    }
+}
+
 impl fmt::Display for Line{
     /// Log line.
     /// # Example
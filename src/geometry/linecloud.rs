@@ -3,7 +3,7 @@ use crate::common::{FromJsonData, HasJsonData, Data};
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 use std::fmt;
-use crate::primitives::Vector;
+use crate::primitives::{Transformable, Vector};
 use crate::geometry::Point;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +60,53 @@ impl LineCloud {
             dirty: true,
         }
     }
+
+    /// Computes the average of the line midpoints, weighted by `weight`.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - The weight of each line, e.g. `|l| l.length()`.
+    ///
+    /// # Returns
+    ///
+    /// The weighted center, or None if the cloud is empty or the weights sum to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{LineCloud, Line, Color};
+    /// let lc = LineCloud::new(
+    ///     vec![Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0), Line::new(0.0, 1.0, 0.0, 3.0, 1.0, 0.0)],
+    ///     vec![Color::red(), Color::red()],
+    /// );
+    /// let center = lc.weighted_center(|l| l.length()).unwrap();
+    /// assert_eq!(center.y, 0.75);
+    /// ```
+    pub fn weighted_center(&self, weight: impl Fn(&Line) -> f32) -> Option<Point> {
+        let mut total = 0.0;
+        let mut center = Point::new(0.0, 0.0, 0.0);
+        for line in &self.lines {
+            let w = weight(line);
+            let m = line.midpoint();
+            center.x += m.x * w;
+            center.y += m.y * w;
+            center.z += m.z * w;
+            total += w;
+        }
+        if total == 0.0 {
+            return None;
+        }
+        Some(Point::new(center.x / total, center.y / total, center.z / total))
+    }
+
+    /// Computes the average of the line midpoints, each line counting equally.
+    ///
+    /// # Returns
+    ///
+    /// The barycenter, or None if the cloud is empty.
+    pub fn barycenter(&self) -> Option<Point> {
+        self.weighted_center(|_| 1.0)
+    }
 }
 
 impl AddAssign<&Vector> for LineCloud {
@@ -200,6 +247,17 @@ impl Sub<&Vector> for LineCloud {
     }
 }
 
+// Implement Transformable for LineCloud
+impl Transformable for LineCloud {
+    /// Transforms all lines in place and marks the pipe meshes for rebuild.
+    fn transform(&mut self, xform: &Xform) {
+        for line in &mut self.lines {
+            line.transform(xform);
+        }
+        self.dirty = true;
+    }
+}
+
 impl fmt::Display for LineCloud {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -304,4 +362,43 @@ impl LineCloud {
         
         &self.meshes
     }
+
+    /// Builds one mesh holding the pipes of all lines, with each line's color baked
+    /// into the vertex colors of its pipe.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{LineCloud, Line, Color, Mesh, Point};
+    /// let lc = LineCloud::new(
+    ///     vec![Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0), Line::new(0.0, 1.0, 0.0, 1.0, 1.0, 0.0)],
+    ///     vec![Color::red(), Color::blue()]
+    /// );
+    /// let mesh = lc.to_combined_mesh();
+    /// assert_eq!(mesh.number_of_faces(), 2 * Mesh::create_pipe(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), 1.0).number_of_faces());
+    /// ```
+    pub fn to_combined_mesh(&self) -> Mesh {
+        let thickness = self.data.get_thickness();
+        let pipes: Vec<Mesh> = self.lines.iter().enumerate()
+            .map(|(i, line)| {
+                let start = Point::new(line.x0, line.y0, line.z0);
+                let end = Point::new(line.x1, line.y1, line.z1);
+                let mut mesh = Mesh::create_pipe(start, end, thickness);
+                if let Some(color) = self.colors.get(i) {
+                    let keys: Vec<usize> = mesh.vertex.keys().copied().collect();
+                    for vk in keys {
+                        mesh.set_vertex_color(vk, color);
+                    }
+                }
+                mesh
+            })
+            .collect();
+        crate::geometry::join_meshes(&pipes)
+    }
+
+    /// Transforms that place the unit pipe on each line, for instanced rendering.
+    /// See `Line::to_pipe_transform`; zero-length lines are skipped.
+    pub fn to_pipe_transforms(&self) -> Vec<Xform> {
+        self.lines.iter().filter_map(|line| line.to_pipe_transform()).collect()
+    }
 }
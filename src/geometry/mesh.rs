@@ -1,7 +1,7 @@
-use crate::geometry::{Point, Line};
+use crate::geometry::{Point, Line, LineCloud, Plane};
 use crate::common::Data;
 use crate::common::{JsonSerializable, FromJsonData};
-use crate::primitives::Xform;
+use crate::primitives::{Color, Transformable, Vector, Xform};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::f32::consts::PI;
@@ -18,6 +18,17 @@ pub enum NormalWeighting {
     Uniform,
 }
 
+/// Subdivision scheme used by `Mesh::subdivide`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubdivScheme {
+    /// Split every n-gon into n quads at its edge midpoints and center, without smoothing
+    Linear,
+    /// Catmull-Clark smoothing for arbitrary polygon meshes (quads out)
+    CatmullClark,
+    /// Loop smoothing for triangle meshes (triangles out)
+    Loop,
+}
+
 /// A halfedge mesh data structure for representing polygonal surfaces.
 /// 
 /// This implementation follows the COMPAS halfedge mesh design, where mesh
@@ -42,6 +53,9 @@ pub struct Mesh {
     pub default_face_attributes: HashMap<String, f32>,
     /// Default edge attributes
     pub default_edge_attributes: HashMap<String, f32>,
+    /// Mesh-level attributes, e.g. the grid dimensions stored by `create_grid`
+    #[serde(default)]
+    pub attributes: HashMap<String, f32>,
     /// Optional cached triangulations per face for viewer rendering (triangles only).
     /// Keyed by face key; each triangle stores vertex keys into `self.vertex`.
     /// Skipped in serialization to keep storage minimal; can be recomputed.
@@ -138,6 +152,38 @@ impl VertexData {
     }
 }
 
+/// Mass properties of a closed mesh, assuming unit density.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassProperties {
+    /// Enclosed volume (equal to the mass at unit density)
+    pub volume: f32,
+    /// Centroid of the enclosed volume
+    pub centroid: Point,
+    /// Inertia tensor about the centroid, row-major
+    pub inertia: [[f32; 3]; 3],
+}
+
+/// Saved topology, geometry and attributes of a mesh, created by `Mesh::snapshot`
+/// and consumed by `Mesh::restore`.
+///
+/// The snapshot is a full copy of the mesh maps, so its size grows with the mesh.
+/// Keep only the snapshots an undo stack actually needs. The mesh guid and name
+/// are not part of the snapshot.
+#[derive(Debug, Clone)]
+pub struct MeshSnapshot {
+    halfedge: HashMap<usize, HashMap<usize, Option<usize>>>,
+    vertex: HashMap<usize, VertexData>,
+    face: HashMap<usize, Vec<usize>>,
+    facedata: HashMap<usize, HashMap<String, f32>>,
+    edgedata: HashMap<(usize, usize), HashMap<String, f32>>,
+    default_vertex_attributes: HashMap<String, f32>,
+    default_face_attributes: HashMap<String, f32>,
+    default_edge_attributes: HashMap<String, f32>,
+    attributes: HashMap<String, f32>,
+    triangulation: HashMap<usize, Vec<[usize; 3]>>,
+    max_vertex: usize,
+    max_face: usize,
+}
 
 
 impl Default for Mesh {
@@ -174,6 +220,7 @@ impl Mesh {
             default_vertex_attributes,
             default_face_attributes: HashMap::new(),
             default_edge_attributes: HashMap::new(),
+            attributes: HashMap::new(),
             triangulation: HashMap::new(),
             max_vertex: 0,
             max_face: 0,
@@ -181,6 +228,82 @@ impl Mesh {
         }
     }
 
+    /// Create a new empty halfedge mesh with room for the given number of vertices and faces.
+    ///
+    /// The result is identical to `Mesh::new()`; the hints only avoid repeated
+    /// reallocation of the internal maps while building large meshes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Mesh;
+    /// let mesh = Mesh::with_capacity(1000, 2000);
+    /// assert!(mesh.is_empty());
+    /// assert!(mesh.vertex.capacity() >= 1000);
+    /// ```
+    pub fn with_capacity(vertices: usize, faces: usize) -> Self {
+        let mut mesh = Mesh::new();
+        mesh.reserve(vertices, faces);
+        mesh
+    }
+
+    /// Reserve capacity for at least `vertices` more vertices and `faces` more faces.
+    pub fn reserve(&mut self, vertices: usize, faces: usize) {
+        self.vertex.reserve(vertices);
+        self.halfedge.reserve(vertices);
+        self.face.reserve(faces);
+    }
+
+    /// Save the current state of the mesh for undo.
+    ///
+    /// Captures vertices, faces, halfedges, attributes and the key counters, so
+    /// `restore` brings back the exact same keys. The snapshot is a full copy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let snapshot = mesh.snapshot();
+    /// mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// mesh.restore(snapshot);
+    /// assert_eq!(mesh.number_of_vertices(), 1);
+    /// ```
+    pub fn snapshot(&self) -> MeshSnapshot {
+        MeshSnapshot {
+            halfedge: self.halfedge.clone(),
+            vertex: self.vertex.clone(),
+            face: self.face.clone(),
+            facedata: self.facedata.clone(),
+            edgedata: self.edgedata.clone(),
+            default_vertex_attributes: self.default_vertex_attributes.clone(),
+            default_face_attributes: self.default_face_attributes.clone(),
+            default_edge_attributes: self.default_edge_attributes.clone(),
+            attributes: self.attributes.clone(),
+            triangulation: self.triangulation.clone(),
+            max_vertex: self.max_vertex,
+            max_face: self.max_face,
+        }
+    }
+
+    /// Restore a state saved with `snapshot`, discarding all changes made since.
+    /// The guid and name of the mesh are kept.
+    pub fn restore(&mut self, snapshot: MeshSnapshot) {
+        self.halfedge = snapshot.halfedge;
+        self.vertex = snapshot.vertex;
+        self.face = snapshot.face;
+        self.facedata = snapshot.facedata;
+        self.edgedata = snapshot.edgedata;
+        self.default_vertex_attributes = snapshot.default_vertex_attributes;
+        self.default_face_attributes = snapshot.default_face_attributes;
+        self.default_edge_attributes = snapshot.default_edge_attributes;
+        self.attributes = snapshot.attributes;
+        self.triangulation = snapshot.triangulation;
+        self.max_vertex = snapshot.max_vertex;
+        self.max_face = snapshot.max_face;
+    }
+
     /// Check if the mesh is empty.
     /// 
     /// # Returns
@@ -316,6 +439,187 @@ impl Mesh {
         v - e + f
     }
 
+    /// Group the faces into edge-connected components.
+    ///
+    /// Two faces are connected when they share an edge; faces touching only at a
+    /// vertex end up in different groups. Each group is sorted ascending and the groups
+    /// are ordered by their smallest face key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let d = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    /// let f1 = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// let f2 = mesh.add_face(vec![b, d, c], None).unwrap();
+    /// assert_eq!(mesh.connected_components(), vec![vec![f1, f2]]);
+    /// ```
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (fk, fverts) in &self.face {
+            let n = fverts.len();
+            for i in 0..n {
+                let (u, v) = (fverts[i], fverts[(i + 1) % n]);
+                edge_faces.entry((u.min(v), u.max(v))).or_default().push(*fk);
+            }
+        }
+
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        let mut seen: HashSet<usize> = HashSet::with_capacity(fkeys.len());
+        let mut components = Vec::new();
+        for start in fkeys {
+            if !seen.insert(start) {
+                continue;
+            }
+            let mut component = vec![start];
+            let mut stack = vec![start];
+            while let Some(fk) = stack.pop() {
+                let fverts = &self.face[&fk];
+                let n = fverts.len();
+                for i in 0..n {
+                    let (u, v) = (fverts[i], fverts[(i + 1) % n]);
+                    for nb in &edge_faces[&(u.min(v), u.max(v))] {
+                        if seen.insert(*nb) {
+                            component.push(*nb);
+                            stack.push(*nb);
+                        }
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// Split the mesh into one mesh per `connected_components` group, built with
+    /// `from_vertices_and_faces`. Only positions and faces are copied; isolated
+    /// vertices are dropped.
+    pub fn split_components(&self) -> Vec<Mesh> {
+        self.connected_components()
+            .into_iter()
+            .map(|component| {
+                let mut index: HashMap<usize, usize> = HashMap::new();
+                let mut vertices = Vec::new();
+                let faces: Vec<Vec<usize>> = component
+                    .iter()
+                    .map(|fk| {
+                        self.face[fk]
+                            .iter()
+                            .map(|vk| {
+                                *index.entry(*vk).or_insert_with(|| {
+                                    vertices.push(self.vertex[vk].position());
+                                    vertices.len() - 1
+                                })
+                            })
+                            .collect()
+                    })
+                    .collect();
+                Mesh::from_vertices_and_faces(&vertices, &faces)
+            })
+            .collect()
+    }
+
+    /// Check that the mesh is a 2-manifold, possibly with boundary.
+    ///
+    /// Every edge must be used by at most two faces, and the faces around every
+    /// vertex must form a single fan (open at a boundary) or a single closed cycle.
+    /// Both checks read `self.face` directly, so they also catch configurations the
+    /// halfedge map can't represent. Isolated vertices are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert!(mesh.is_manifold());
+    /// ```
+    pub fn is_manifold(&self) -> bool {
+        let mut edge_count: HashMap<(usize, usize), usize> = HashMap::new();
+        // For every vertex, the edges (prev, next) of its link around each incident face
+        let mut links: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+        for fverts in self.face.values() {
+            let n = fverts.len();
+            for i in 0..n {
+                let (prev, curr, next) = (fverts[(i + n - 1) % n], fverts[i], fverts[(i + 1) % n]);
+                let count = edge_count.entry((curr.min(next), curr.max(next))).or_insert(0);
+                *count += 1;
+                if *count > 2 {
+                    return false;
+                }
+                links.entry(curr).or_default().push((prev, next));
+            }
+        }
+
+        // A fan or cycle: the link is connected, no neighbor has more than two link
+        // edges, and it has as many edges as neighbors (cycle) or one fewer (fan)
+        for link in links.values() {
+            let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+            for &(a, b) in link {
+                adjacency.entry(a).or_default().push(b);
+                adjacency.entry(b).or_default().push(a);
+            }
+            if adjacency.values().any(|nbrs| nbrs.len() > 2) {
+                return false;
+            }
+            if link.len() != adjacency.len() && link.len() + 1 != adjacency.len() {
+                return false;
+            }
+            let start = link[0].0;
+            let mut seen: HashSet<usize> = HashSet::from([start]);
+            let mut stack = vec![start];
+            while let Some(a) = stack.pop() {
+                for b in &adjacency[&a] {
+                    if seen.insert(*b) {
+                        stack.push(*b);
+                    }
+                }
+            }
+            if seen.len() != adjacency.len() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Reserve the next available vertex key without adding a vertex.
+    ///
+    /// The key is never handed out again by `add_vertex(p, None)`, so it can be used
+    /// later with `add_vertex(p, Some(key))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let reserved = mesh.reserve_vertex_key();
+    /// let auto = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// assert!(auto > reserved);
+    /// assert_eq!(mesh.add_vertex(Point::new(1.0, 0.0, 0.0), Some(reserved)), reserved);
+    /// ```
+    pub fn reserve_vertex_key(&mut self) -> usize {
+        self.max_vertex += 1;
+        self.max_vertex
+    }
+
+    /// Reserve the next available face key without adding a face.
+    ///
+    /// The key is never handed out again by `add_face(vertices, None)`, so it can be
+    /// used later with `add_face(vertices, Some(key))`.
+    pub fn reserve_face_key(&mut self) -> usize {
+        self.max_face += 1;
+        self.max_face
+    }
+
     /// Add a vertex to the mesh.
     /// 
     /// # Arguments
@@ -428,6 +732,95 @@ impl Mesh {
         Some(face_key)
     }
 
+    /// Remove a face from the mesh.
+    ///
+    /// The face's halfedges become boundary halfedges; edges that no longer border
+    /// any face are removed together with their edge attributes. The vertices are kept,
+    /// see `remove_isolated_vertices` to clean them up.
+    ///
+    /// # Returns
+    /// False if the face doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// assert!(mesh.remove_face(f));
+    /// assert_eq!(mesh.number_of_faces(), 0);
+    /// assert_eq!(mesh.number_of_edges(), 0);
+    /// assert!(!mesh.remove_face(f));
+    /// ```
+    pub fn remove_face(&mut self, fkey: usize) -> bool {
+        let Some(vertices) = self.face.remove(&fkey) else {
+            return false;
+        };
+        self.facedata.remove(&fkey);
+        self.triangulation.remove(&fkey);
+
+        for i in 0..vertices.len() {
+            let u = vertices[i];
+            let v = vertices[(i + 1) % vertices.len()];
+            let opposite_empty = self
+                .halfedge
+                .get(&v)
+                .and_then(|neigh| neigh.get(&u))
+                .is_none_or(|face| face.is_none());
+            if opposite_empty {
+                if let Some(neigh) = self.halfedge.get_mut(&u) {
+                    neigh.remove(&v);
+                }
+                if let Some(neigh) = self.halfedge.get_mut(&v) {
+                    neigh.remove(&u);
+                }
+                self.edgedata.remove(&(u, v));
+                self.edgedata.remove(&(v, u));
+            } else if let Some(neigh) = self.halfedge.get_mut(&u) {
+                neigh.insert(v, None);
+            }
+        }
+        true
+    }
+
+    /// Remove a vertex that is not used by any face.
+    ///
+    /// Callers must remove the incident faces first (`remove_face`); a vertex that is
+    /// still part of a face is left untouched. Its halfedges, the opposite halfedges
+    /// pointing to it and their edge attributes are removed with it.
+    ///
+    /// # Returns
+    /// False if the vertex doesn't exist or is still referenced by a face.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let v = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// assert!(mesh.remove_vertex(v));
+    /// assert_eq!(mesh.number_of_vertices(), 0);
+    /// assert!(!mesh.remove_vertex(v));
+    /// ```
+    pub fn remove_vertex(&mut self, vkey: usize) -> bool {
+        if !self.vertex.contains_key(&vkey) || self.face.values().any(|fverts| fverts.contains(&vkey)) {
+            return false;
+        }
+        self.vertex.remove(&vkey);
+        if let Some(neigh) = self.halfedge.remove(&vkey) {
+            for nb in neigh.keys() {
+                if let Some(back) = self.halfedge.get_mut(nb) {
+                    back.remove(&vkey);
+                }
+            }
+        }
+        self.edgedata.retain(|(u, v), _| *u != vkey && *v != vkey);
+        true
+    }
+
     /// Invalidate triangulation cache for all faces.
     pub fn invalidate_all_triangulation(&mut self) {
         self.triangulation.clear();
@@ -461,6 +854,15 @@ impl Mesh {
         self.ear_clip_triangulate_vertices(face_vertices)
     }
 
+    /// Corner positions of the triangles of a face, triangulated like
+    /// `triangulate_face_vertices`. Triangles touching a missing vertex are skipped.
+    fn face_triangle_points(&self, face_vertices: &Vec<usize>) -> Vec<[Point; 3]> {
+        self.triangulate_face_vertices(face_vertices)
+            .into_iter()
+            .filter_map(|[a, b, c]| Some([self.vertex_position(a)?, self.vertex_position(b)?, self.vertex_position(c)?]))
+            .collect()
+    }
+
     /// Ear clipping triangulation of a simple 3D polygon (projected to a dominant plane).
     /// No holes supported. Returns triangles as vertex keys in original order.
     fn ear_clip_triangulate_vertices(&self, vkeys: &Vec<usize>) -> Vec<[usize; 3]> {
@@ -701,6 +1103,103 @@ impl Mesh {
         self.vertex.get(&vertex_key).map(|v| v.position())
     }
 
+    /// Set the color of a vertex, stored as normalized `r`, `g`, `b` attributes.
+    ///
+    /// # Returns
+    /// `false` if the vertex doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point, Color};
+    /// let mut mesh = Mesh::new();
+    /// let v = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// assert!(mesh.set_vertex_color(v, &Color::red()));
+    /// assert_eq!(mesh.vertex_color(v), Some(Color::red()));
+    /// ```
+    pub fn set_vertex_color(&mut self, vertex_key: usize, color: &Color) -> bool {
+        match self.vertex.get_mut(&vertex_key) {
+            Some(vd) => {
+                let (r, g, b, _) = color.to_float();
+                vd.set_color(r, g, b);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the color of a vertex from its `r`, `g`, `b` attributes (opaque).
+    pub fn vertex_color(&self, vertex_key: usize) -> Option<Color> {
+        self.vertex.get(&vertex_key).map(|vd| {
+            let [r, g, b] = vd.color();
+            Color::from_float(r, g, b, 1.0)
+        })
+    }
+
+    /// Set the color of a face, stored as `r`, `g`, `b` face attributes in [0, 1].
+    ///
+    /// # Returns
+    /// False if the face doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point, Color};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// assert!(mesh.set_face_color(f, &Color::blue()));
+    /// assert_eq!(mesh.face_color(f), Some(Color::blue()));
+    /// ```
+    pub fn set_face_color(&mut self, face_key: usize, color: &Color) -> bool {
+        if !self.face.contains_key(&face_key) {
+            return false;
+        }
+        let (r, g, b, _) = color.to_float();
+        let attrs = self.facedata.entry(face_key).or_default();
+        attrs.insert("r".to_string(), r);
+        attrs.insert("g".to_string(), g);
+        attrs.insert("b".to_string(), b);
+        true
+    }
+
+    /// Get the color of a face from its `r`, `g`, `b` attributes (opaque).
+    ///
+    /// # Returns
+    /// None if the face has no color attributes.
+    pub fn face_color(&self, face_key: usize) -> Option<Color> {
+        let attrs = self.facedata.get(&face_key)?;
+        let r = *attrs.get("r")?;
+        let g = *attrs.get("g")?;
+        let b = *attrs.get("b")?;
+        Some(Color::from_float(r, g, b, 1.0))
+    }
+
+    /// Set every vertex color to the average color of its incident faces.
+    /// Faces without a color are skipped; vertices without colored faces keep their color.
+    pub fn face_colors_to_vertex_colors(&mut self) {
+        let mut sums: HashMap<usize, ([f32; 3], usize)> = HashMap::new();
+        for (fk, fverts) in &self.face {
+            let Some(attrs) = self.facedata.get(fk) else { continue };
+            let (Some(r), Some(g), Some(b)) = (attrs.get("r"), attrs.get("g"), attrs.get("b")) else { continue };
+            for vk in fverts {
+                let (sum, count) = sums.entry(*vk).or_insert(([0.0; 3], 0));
+                sum[0] += r;
+                sum[1] += g;
+                sum[2] += b;
+                *count += 1;
+            }
+        }
+        for (vk, (sum, count)) in sums {
+            if let Some(vd) = self.vertex.get_mut(&vk) {
+                let n = count as f32;
+                vd.set_color(sum[0] / n, sum[1] / n, sum[2] / n);
+            }
+        }
+    }
+
     /// Get the vertices of a face.
     /// 
     /// # Arguments
@@ -751,38 +1250,29 @@ impl Mesh {
 
     /// Build a mesh from polygons, merging vertices within an optional precision.
     ///
-    /// - If `precision` is Some(eps), vertices whose coordinates are within eps are merged
-    ///   using integer grid quantization (round(x/eps)).
-    /// - If `precision` is None, only exactly equal coordinates are merged (bitwise equality).
+    /// - If `precision` is Some(eps), vertices are merged when they fall into the same
+    ///   integer grid cell `(round(x/eps), round(y/eps), round(z/eps))`. The key is computed
+    ///   once per coordinate, so precision is applied exactly once regardless of magnitude.
+    /// - If `precision` is None (or not positive), only exactly equal coordinates are merged
+    ///   (bitwise equality, treating -0.0 and 0.0 as equal).
     pub fn from_polygons_with_merge(polygons: Vec<Vec<Point>>, precision: Option<f32>) -> Self {
         use std::collections::HashMap;
 
-        let mut mesh = Mesh::new();
+        // Estimate the vertex count from Euler's formula for a closed mesh:
+        // V ~ E - F with E ~ corners / 2 (triangles: V ~ F / 2, quads: V ~ F)
+        let corners: usize = polygons.iter().filter(|poly| poly.len() >= 3).map(|poly| poly.len()).sum();
+        let vertex_estimate = (corners / 2).saturating_sub(polygons.len());
+        let mut mesh = Mesh::with_capacity(vertex_estimate, polygons.len());
 
-        // Maps for vertex deduplication
-        let mut map_eps: HashMap<(i64, i64, i64), usize> = HashMap::new();
-        let mut map_exact: HashMap<(u64, u64, u64), usize> = HashMap::new();
+        // Vertex deduplication on integer grid keys, see `weld_key`
         let eps = precision.unwrap_or(0.0);
-        let use_eps = eps > 0.0;
+        let mut welded: HashMap<(i64, i64, i64), usize> = HashMap::with_capacity(vertex_estimate);
 
         // Helper to get or create a vertex key for a given point
         let mut get_vkey = |p: &Point, mesh: &mut Mesh| -> usize {
-            if use_eps {
-                let kx = (p.x / eps).round() as i64;
-                let ky = (p.y / eps).round() as i64;
-                let kz = (p.z / eps).round() as i64;
-                let key = (kx, ky, kz);
-                if let Some(&vk) = map_eps.get(&key) { return vk; }
-                let vk = mesh.add_vertex(p.clone(), None);
-                map_eps.insert(key, vk);
-                vk
-            } else {
-                let key = (p.x.to_bits() as u64, p.y.to_bits() as u64, p.z.to_bits() as u64);
-                if let Some(&vk) = map_exact.get(&key) { return vk; }
-                let vk = mesh.add_vertex(p.clone(), None);
-                map_exact.insert(key, vk);
-                vk
-            }
+            *welded
+                .entry(Self::weld_key(p, eps))
+                .or_insert_with(|| mesh.add_vertex(*p, None))
         };
 
         for poly in polygons.into_iter() {
@@ -804,6 +1294,209 @@ impl Mesh {
         Self::from_polygons_with_merge(polygons, precision)
     }
 
+    /// Integer key of the weld cell containing `p`.
+    ///
+    /// With a positive `cell` the key is `(round(x/cell), round(y/cell), round(z/cell))`;
+    /// otherwise it is the bit pattern of the coordinates, so only exactly equal points
+    /// (with -0.0 folded into 0.0) share a key.
+    fn weld_key(p: &Point, cell: f32) -> (i64, i64, i64) {
+        if cell > 0.0 {
+            (
+                (p.x / cell).round() as i64,
+                (p.y / cell).round() as i64,
+                (p.z / cell).round() as i64,
+            )
+        } else {
+            // Adding 0.0 folds -0.0 into +0.0 so both signs of zero share a key
+            (
+                (p.x + 0.0).to_bits() as i64,
+                (p.y + 0.0).to_bits() as i64,
+                (p.z + 0.0).to_bits() as i64,
+            )
+        }
+    }
+
+    /// Merge vertices that fall into the same integer grid cell of size `cell`.
+    ///
+    /// The vertex with the smallest key in each cell is kept, faces are remapped onto
+    /// it, and faces that collapse (fewer than 3 distinct vertices) are removed.
+    /// A `cell` that is zero or negative merges only exactly equal positions.
+    /// This is the welder used by `from_polygons`.
+    ///
+    /// # Returns
+    /// The number of vertices removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let d = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let e = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    /// let f = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// mesh.add_face(vec![d, e, f], None);
+    /// assert_eq!(mesh.weld_grid(1e-3), 2);
+    /// assert_eq!(mesh.number_of_vertices(), 4);
+    /// ```
+    pub fn weld_grid(&mut self, cell: f32) -> usize {
+        let mut vkeys: Vec<usize> = self.vertex.keys().copied().collect();
+        vkeys.sort_unstable();
+
+        let mut cells: HashMap<(i64, i64, i64), usize> = HashMap::with_capacity(vkeys.len());
+        let mut remap: HashMap<usize, usize> = HashMap::new();
+        for vk in vkeys {
+            let key = Self::weld_key(&self.vertex[&vk].position(), cell);
+            let keep = *cells.entry(key).or_insert(vk);
+            if keep != vk {
+                remap.insert(vk, keep);
+            }
+        }
+        if remap.is_empty() {
+            return 0;
+        }
+
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        for fk in fkeys {
+            let mut fverts: Vec<usize> = Vec::with_capacity(self.face[&fk].len());
+            for vk in &self.face[&fk] {
+                let vk = *remap.get(vk).unwrap_or(vk);
+                if fverts.last() != Some(&vk) {
+                    fverts.push(vk);
+                }
+            }
+            if fverts.len() > 1 && fverts.first() == fverts.last() {
+                fverts.pop();
+            }
+            let distinct: HashSet<usize> = fverts.iter().copied().collect();
+            if fverts.len() < 3 || distinct.len() != fverts.len() {
+                self.face.remove(&fk);
+                self.facedata.remove(&fk);
+            } else {
+                self.face.insert(fk, fverts);
+            }
+        }
+
+        for vk in remap.keys() {
+            self.vertex.remove(vk);
+        }
+        let edgedata = std::mem::take(&mut self.edgedata);
+        for ((u, v), attrs) in edgedata {
+            let (u, v) = (*remap.get(&u).unwrap_or(&u), *remap.get(&v).unwrap_or(&v));
+            if u != v {
+                self.edgedata.entry((u, v)).or_insert(attrs);
+            }
+        }
+        self.triangulation.clear();
+        self.rebuild_halfedges();
+        remap.len()
+    }
+
+    /// Weld vertices whose positions match within `precision`, using the same
+    /// bucketing as `from_polygons(polygons, Some(precision))`.
+    ///
+    /// Convenience wrapper that forwards to `weld_grid`; faces are rewired to the
+    /// surviving vertex keys and halfedges are rebuilt. Typically used after `merge`
+    /// to stitch parts into one watertight mesh.
+    ///
+    /// # Returns
+    /// The number of vertices removed.
+    pub fn weld_vertices(&mut self, precision: f32) -> usize {
+        self.weld_grid(precision)
+    }
+
+    /// Re-express the mesh in the local coordinates of `plane`, so the plane becomes the
+    /// world XY plane. Keys, faces and attributes are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Plane, Point, Vector};
+    /// let plane = Plane::from_point_normal(&Point::new(0.0, 0.0, 3.0), &Vector::new(0.0, 0.0, 1.0));
+    /// let mut mesh = Mesh::new();
+    /// let v = mesh.add_vertex(Point::new(1.0, 2.0, 3.0), None);
+    /// let local = mesh.to_plane_coordinates(&plane);
+    /// assert_eq!(local.vertex_position(v).unwrap().z, 0.0);
+    /// ```
+    pub fn to_plane_coordinates(&self, plane: &Plane) -> Mesh {
+        self.transformed(&Xform::plane_to_xy(&plane.origin, &plane.xaxis, &plane.yaxis, &plane.zaxis))
+    }
+
+    /// Map a mesh from the local coordinates of `plane` back to world coordinates,
+    /// the inverse of `to_plane_coordinates`.
+    pub fn from_plane_coordinates(&self, plane: &Plane) -> Mesh {
+        self.transformed(&Xform::xy_to_plane(&plane.origin, &plane.xaxis, &plane.yaxis, &plane.zaxis))
+    }
+
+    /// Map each vertex key to its index in `to_vertices_and_faces`: keys sorted
+    /// ascending and numbered from 0.
+    pub fn vertex_index_map(&self) -> HashMap<usize, usize> {
+        let mut keys: Vec<usize> = self.vertex.keys().copied().collect();
+        keys.sort_unstable();
+        keys.into_iter().enumerate().map(|(i, k)| (k, i)).collect()
+    }
+
+    /// Export the mesh as flat vertex and face lists with contiguous indices.
+    ///
+    /// Vertices are ordered by key and faces by face key, so the output is
+    /// deterministic even when keys are sparse. Face entries index into the vertex
+    /// list, see `vertex_index_map`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), Some(10));
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), Some(20));
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), Some(30));
+    /// mesh.add_face(vec![a, b, c], None);
+    /// let (vertices, faces) = mesh.to_vertices_and_faces();
+    /// assert_eq!(vertices.len(), 3);
+    /// assert_eq!(faces, vec![vec![0, 1, 2]]);
+    /// ```
+    pub fn to_vertices_and_faces(&self) -> (Vec<Point>, Vec<Vec<usize>>) {
+        let index = self.vertex_index_map();
+        let mut vertices = vec![Point::default(); index.len()];
+        for (k, i) in &index {
+            vertices[*i] = self.vertex[k].position();
+        }
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        let faces = fkeys
+            .iter()
+            .map(|fk| self.face[fk].iter().map(|vk| index[vk]).collect())
+            .collect();
+        (vertices, faces)
+    }
+
+    /// Create a mesh from flat vertex and face lists, the inverse of
+    /// `to_vertices_and_faces`. Face entries index into `vertices`; faces with an
+    /// out-of-range index or fewer than 3 distinct vertices are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let vertices = vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)];
+    /// let mesh = Mesh::from_vertices_and_faces(&vertices, &[vec![0, 1, 2]]);
+    /// assert_eq!(mesh.number_of_faces(), 1);
+    /// ```
+    pub fn from_vertices_and_faces(vertices: &[Point], faces: &[Vec<usize>]) -> Mesh {
+        let mut mesh = Mesh::with_capacity(vertices.len(), faces.len());
+        let keys: Vec<usize> = vertices.iter().map(|p| mesh.add_vertex(*p, None)).collect();
+        for face in faces {
+            if face.iter().all(|i| *i < keys.len()) {
+                mesh.add_face(face.iter().map(|i| keys[*i]).collect(), None);
+            }
+        }
+        mesh
+    }
+
     /// Export mesh as separate buffers compatible with `ModelMesh`.
     /// Returns (positions, indices, normals, colors, vertex_count, triangle_count).
     pub fn to_model_mesh_buffers(&mut self) -> (Vec<f32>, Vec<u32>, Vec<f32>, Vec<f32>, usize, usize) {
@@ -941,9 +1634,1431 @@ impl Mesh {
         false
     }
 
-    /// Extract all unique edges of the mesh as Line objects.
-    /// This includes both boundary and interior edges.
-    pub fn extract_edges_as_lines(&self) -> Vec<Line> {
+    /// Compute the unit normal of a face using Newell's method (robust for non-planar polygons).
+    ///
+    /// # Returns
+    /// The normal, or None if the face doesn't exist or is degenerate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// assert_eq!(mesh.face_normal(f).unwrap().z, 1.0);
+    /// ```
+    pub fn face_normal(&self, face_key: usize) -> Option<Vector> {
+        let mut n = self.face_vector_area(face_key)?;
+        if n.unitize() { Some(n) } else { None }
+    }
+
+    /// Compute the area of a face from its Newell vector (exact for planar faces).
+    ///
+    /// # Returns
+    /// None if the face doesn't exist or has fewer than 3 vertices.
+    pub fn face_area(&self, face_key: usize) -> Option<f32> {
+        Some(self.face_vector_area(face_key)?.length())
+    }
+
+    /// Interior angles of a face in radians, one per corner in face vertex order.
+    /// The angle at a corner is measured between its two adjacent edge vectors;
+    /// corners with a zero-length edge report 0.
+    ///
+    /// # Returns
+    /// An empty vector if the face doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// let angles = mesh.face_angles(f);
+    /// assert!((angles[0] - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn face_angles(&self, face_key: usize) -> Vec<f32> {
+        match self.face.get(&face_key) {
+            Some(fverts) => self.polygon_corner_angles(fverts),
+            None => Vec::new(),
+        }
+    }
+
+    /// Smallest interior angle of a face in radians, useful for finding sliver faces.
+    ///
+    /// # Returns
+    /// None if the face doesn't exist or has no corners.
+    pub fn face_min_angle(&self, face_key: usize) -> Option<f32> {
+        self.face_angles(face_key).into_iter().reduce(f32::min)
+    }
+
+    /// Best-fit plane of a face, through the average of its vertices.
+    ///
+    /// The normal is the Newell normal of `face_normal`, which treats all corners
+    /// alike and follows the face winding.
+    ///
+    /// # Returns
+    /// None if the face doesn't exist, has fewer than 3 vertices or is degenerate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 2.0), None);
+    /// let b = mesh.add_vertex(Point::new(3.0, 0.0, 2.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 3.0, 2.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// let plane = mesh.face_plane(f).unwrap();
+    /// assert_eq!((plane.origin.x, plane.origin.y, plane.origin.z), (1.0, 1.0, 2.0));
+    /// assert_eq!(plane.zaxis.z, 1.0);
+    /// ```
+    pub fn face_plane(&self, face_key: usize) -> Option<Plane> {
+        if self.face.get(&face_key)?.len() < 3 {
+            return None;
+        }
+        let centroid = self.face_centroid(face_key)?;
+        Some(Plane::from_point_normal(&centroid, &self.face_normal(face_key)?))
+    }
+
+    /// Average of the vertex positions of a face.
+    ///
+    /// # Returns
+    /// None if the face doesn't exist or has no vertices.
+    pub fn face_centroid(&self, face_key: usize) -> Option<Point> {
+        let points: Vec<Point> = self.face.get(&face_key)?.iter().filter_map(|vk| self.vertex_position(*vk)).collect();
+        Point::centroid(&points)
+    }
+
+    /// Planarity error of a face: the largest distance of its vertices to `face_plane`.
+    ///
+    /// # Returns
+    /// None if the face doesn't exist, has fewer than 3 vertices or is degenerate.
+    pub fn face_planarity(&self, face_key: usize) -> Option<f32> {
+        let plane = self.face_plane(face_key)?;
+        self.face[&face_key]
+            .iter()
+            .filter_map(|vk| self.vertex_position(*vk))
+            .map(|p| plane.to_uv(&p).2.abs())
+            .reduce(f32::max)
+    }
+
+    /// Intersect a ray with the faces of the mesh and return the nearest hit.
+    ///
+    /// Faces are ear clipped and each triangle is tested with the Möller–Trumbore
+    /// algorithm. Hits behind the origin are ignored.
+    ///
+    /// # Arguments
+    /// * `origin` - Start of the ray.
+    /// * `direction` - Ray direction; need not be unit length.
+    ///
+    /// # Returns
+    /// The ray parameter `t` of the hit (`origin + t * direction`) and the face key,
+    /// or None if the ray misses.
+    pub fn ray_intersect(&self, origin: &Point, direction: &Vector) -> Option<(f32, usize)> {
+        let mut nearest: Option<(f32, usize)> = None;
+        for (&fk, fverts) in &self.face {
+            for [a, b, c] in self.face_triangle_points(fverts) {
+                let e1 = Vector::new(b.x - a.x, b.y - a.y, b.z - a.z);
+                let e2 = Vector::new(c.x - a.x, c.y - a.y, c.z - a.z);
+                let p = direction.cross(&e2);
+                let det = e1.dot(&p);
+                if det.abs() < 1e-12 {
+                    continue;
+                }
+                let inv = 1.0 / det;
+                let s = Vector::new(origin.x - a.x, origin.y - a.y, origin.z - a.z);
+                let u = s.dot(&p) * inv;
+                if !(0.0..=1.0).contains(&u) {
+                    continue;
+                }
+                let q = s.cross(&e1);
+                let v = direction.dot(&q) * inv;
+                if v < 0.0 || u + v > 1.0 {
+                    continue;
+                }
+                let t = e2.dot(&q) * inv;
+                if t >= 0.0 && nearest.is_none_or(|(best, _)| t < best) {
+                    nearest = Some((t, fk));
+                }
+            }
+        }
+        nearest
+    }
+
+    /// Newell vector of a face scaled to its area: half the sum of the edge cross terms.
+    fn face_vector_area(&self, face_key: usize) -> Option<Vector> {
+        let fverts = self.face.get(&face_key)?;
+        let points: Vec<Point> = fverts.iter().filter_map(|vk| self.vertex_position(*vk)).collect();
+        if points.len() < 3 {
+            return None;
+        }
+        let mut n = Vector::new(0.0, 0.0, 0.0);
+        for i in 0..points.len() {
+            let p = &points[i];
+            let q = &points[(i + 1) % points.len()];
+            n.x += (p.y - q.y) * (p.z + q.z);
+            n.y += (p.z - q.z) * (p.x + q.x);
+            n.z += (p.x - q.x) * (p.y + q.y);
+        }
+        Some(n * 0.5)
+    }
+
+    /// Return the keys of the vertices connected to a vertex by an edge, sorted ascending.
+    pub fn vertex_neighbors(&self, vertex_key: usize) -> Vec<usize> {
+        let mut out: Vec<usize> = self
+            .halfedge
+            .get(&vertex_key)
+            .map(|n| n.keys().copied().collect())
+            .unwrap_or_default();
+        out.sort_unstable();
+        out
+    }
+
+    /// Return the keys of the faces around a vertex, sorted ascending.
+    pub fn vertex_faces(&self, vertex_key: usize) -> Vec<usize> {
+        let mut out: Vec<usize> = self
+            .halfedge
+            .get(&vertex_key)
+            .map(|n| n.values().filter_map(|f| *f).collect())
+            .unwrap_or_default();
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+
+    /// Compute the area-weighted normal of a vertex from its incident faces.
+    ///
+    /// # Returns
+    /// None if the vertex has no faces or their normals cancel out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert_eq!(mesh.vertex_normal(a).unwrap().z, 1.0);
+    /// ```
+    pub fn vertex_normal(&self, vertex_key: usize) -> Option<Vector> {
+        let mut n = Vector::new(0.0, 0.0, 0.0);
+        for fk in self.vertex_faces(vertex_key) {
+            if let Some(area) = self.face_vector_area(fk) {
+                n.x += area.x;
+                n.y += area.y;
+                n.z += area.z;
+            }
+        }
+        if n.unitize() { Some(n) } else { None }
+    }
+
+    /// Compute the dihedral angle of edge (u, v) as the angle in radians between the
+    /// normals of its two adjacent faces (0 for coplanar faces).
+    ///
+    /// # Returns
+    /// None if the edge doesn't exist, lies on the boundary, or a face is degenerate.
+    pub fn dihedral_angle(&self, u: usize, v: usize) -> Option<f32> {
+        let f1 = (*self.halfedge.get(&u)?.get(&v)?)?;
+        let f2 = (*self.halfedge.get(&v)?.get(&u)?)?;
+        let n1 = self.face_normal(f1)?;
+        let n2 = self.face_normal(f2)?;
+        Some(n1.angle(&n2))
+    }
+
+    /// Return feature edges: all boundary edges plus interior edges whose dihedral angle
+    /// exceeds `angle_threshold` (radians). Edges are returned as sorted `(min, max)`
+    /// vertex pairs, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Mesh;
+    /// let grid = Mesh::create_grid(1.0, 1.0, 2, 2);
+    /// // A flat grid only has its 8 boundary edges as features
+    /// assert_eq!(grid.feature_edges(0.1).len(), 8);
+    /// ```
+    pub fn feature_edges(&self, angle_threshold: f32) -> Vec<(usize, usize)> {
+        self.edges()
+            .into_iter()
+            .filter(|&(u, v)| {
+                if self.is_edge_on_boundary(u, v) {
+                    return true;
+                }
+                self.dihedral_angle(u, v).is_some_and(|angle| angle > angle_threshold)
+            })
+            .collect()
+    }
+
+    /// Return every undirected edge once as a `(u, v)` pair with `u < v`, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert_eq!(mesh.edges().len(), 3);
+    /// ```
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        let mut out: Vec<(usize, usize)> = self
+            .halfedge
+            .iter()
+            .flat_map(|(&u, neigh)| neigh.keys().map(move |&v| if u < v { (u, v) } else { (v, u) }))
+            .collect();
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+
+    /// Euclidean length of the edge `(u, v)`, in either direction.
+    ///
+    /// # Returns
+    /// None if `(u, v)` is not an edge of the mesh.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(3.0, 4.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 4.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert_eq!(mesh.edge_length(b, a), Some(5.0));
+    /// ```
+    pub fn edge_length(&self, u: usize, v: usize) -> Option<f32> {
+        if !self.halfedge.get(&u)?.contains_key(&v) {
+            return None;
+        }
+        Some(self.vertex_position(u)?.distance(&self.vertex_position(v)?))
+    }
+
+    /// Cotangent weights of a triangle mesh, keyed by `(u, v)` with `u < v`.
+    ///
+    /// The weight of an edge is `0.5 * (cot α + cot β)`, where α and β are the angles
+    /// opposite the edge in its two adjacent triangles. Boundary edges only get the
+    /// term of their single triangle. Degenerate triangles contribute nothing.
+    ///
+    /// # Returns
+    /// An empty map if the mesh has a face that is not a triangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// // The hypotenuse is opposite the right angle: cot(90°) = 0
+    /// assert!(mesh.cotangent_weights()[&(b, c)].abs() < 1e-6);
+    /// ```
+    pub fn cotangent_weights(&self) -> HashMap<(usize, usize), f32> {
+        let mut weights: HashMap<(usize, usize), f32> = HashMap::new();
+        if self.face.values().any(|f| f.len() != 3) {
+            return weights;
+        }
+        for fverts in self.face.values() {
+            let Some(points) = fverts.iter().map(|&v| self.vertex_position(v)).collect::<Option<Vec<Point>>>() else {
+                continue;
+            };
+            for i in 0..3 {
+                let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+                let p = points[i];
+                let e1 = Vector::new(points[j].x - p.x, points[j].y - p.y, points[j].z - p.z);
+                let e2 = Vector::new(points[k].x - p.x, points[k].y - p.y, points[k].z - p.z);
+                let sin = e1.cross(&e2).length();
+                if sin < 1e-12 {
+                    continue;
+                }
+                let (u, v) = (fverts[j].min(fverts[k]), fverts[j].max(fverts[k]));
+                *weights.entry((u, v)).or_insert(0.0) += 0.5 * e1.dot(&e2) / sin;
+            }
+        }
+        weights
+    }
+
+    /// Lengths of the circumcentric dual edges of a triangle mesh, keyed by `(u, v)` with `u < v`.
+    ///
+    /// The dual edge connects the circumcenters of the adjacent triangles, and its
+    /// length equals the cotangent weight times the primal edge length.
+    ///
+    /// # Returns
+    /// An empty map if the mesh has a face that is not a triangle.
+    pub fn dual_edge_lengths(&self) -> HashMap<(usize, usize), f32> {
+        self.cotangent_weights()
+            .into_iter()
+            .filter_map(|((u, v), w)| {
+                Some(((u, v), w * self.edge_length(u, v)?))
+            })
+            .collect()
+    }
+
+    /// Return true if either halfedge of edge (u, v) has no face.
+    pub fn is_edge_on_boundary(&self, u: usize, v: usize) -> bool {
+        let uv = self.halfedge.get(&u).and_then(|n| n.get(&v)).copied().flatten();
+        let vu = self.halfedge.get(&v).and_then(|n| n.get(&u)).copied().flatten();
+        uv.is_none() || vu.is_none()
+    }
+
+    /// Return the keys of the faces incident to the undirected edge (u, v).
+    ///
+    /// # Returns
+    /// Two faces for an interior edge, one for a boundary edge and none if the edge
+    /// doesn't exist. The face of halfedge (u, v) comes first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// assert_eq!(mesh.edge_faces(b, a), vec![f]);
+    /// ```
+    pub fn edge_faces(&self, u: usize, v: usize) -> Vec<usize> {
+        let uv = self.halfedge.get(&u).and_then(|n| n.get(&v)).copied().flatten();
+        let vu = self.halfedge.get(&v).and_then(|n| n.get(&u)).copied().flatten();
+        uv.into_iter().chain(vu).collect()
+    }
+
+    /// Face on the left of the halfedge `(u, v)`.
+    ///
+    /// # Returns
+    /// None if the halfedge doesn't exist or is a boundary halfedge.
+    pub fn halfedge_face(&self, u: usize, v: usize) -> Option<usize> {
+        self.halfedge.get(&u)?.get(&v).copied().flatten()
+    }
+
+    /// Position of the halfedge `(u, v)` in its face: the face vertices and the index of `u`.
+    fn halfedge_corner(&self, u: usize, v: usize) -> Option<(&Vec<usize>, usize)> {
+        let fverts = self.face.get(&self.halfedge_face(u, v)?)?;
+        let n = fverts.len();
+        let i = (0..n).find(|i| fverts[*i] == u && fverts[(i + 1) % n] == v)?;
+        Some((fverts, i))
+    }
+
+    /// Next halfedge around the face of `(u, v)`, following the face vertex order.
+    ///
+    /// # Returns
+    /// None if `(u, v)` is not a halfedge of a face.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert_eq!(mesh.halfedge_next(a, b), Some((b, c)));
+    /// assert_eq!(mesh.halfedge_prev(a, b), Some((c, a)));
+    /// ```
+    pub fn halfedge_next(&self, u: usize, v: usize) -> Option<(usize, usize)> {
+        let (fverts, i) = self.halfedge_corner(u, v)?;
+        Some((v, fverts[(i + 2) % fverts.len()]))
+    }
+
+    /// Previous halfedge around the face of `(u, v)`, see `halfedge_next`.
+    pub fn halfedge_prev(&self, u: usize, v: usize) -> Option<(usize, usize)> {
+        let (fverts, i) = self.halfedge_corner(u, v)?;
+        let n = fverts.len();
+        Some((fverts[(i + n - 1) % n], u))
+    }
+
+    /// Return the keys of vertices that are not referenced by any face, sorted ascending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let v = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// assert_eq!(mesh.isolated_vertices(), vec![v]);
+    /// ```
+    pub fn isolated_vertices(&self) -> Vec<usize> {
+        let used: HashSet<usize> = self.face.values().flatten().copied().collect();
+        let mut out: Vec<usize> = self
+            .vertex
+            .keys()
+            .filter(|vk| !used.contains(vk))
+            .copied()
+            .collect();
+        out.sort_unstable();
+        out
+    }
+
+    /// Remove all isolated vertices and any halfedge or edge entries that still reference them.
+    ///
+    /// # Returns
+    /// The number of vertices removed.
+    pub fn remove_isolated_vertices(&mut self) -> usize {
+        let isolated = self.isolated_vertices();
+        for vk in &isolated {
+            self.vertex.remove(vk);
+            self.halfedge.remove(vk);
+            for neigh in self.halfedge.values_mut() {
+                neigh.remove(vk);
+            }
+        }
+        if !isolated.is_empty() {
+            let removed: HashSet<usize> = isolated.iter().copied().collect();
+            self.edgedata
+                .retain(|(u, v), _| !removed.contains(u) && !removed.contains(v));
+        }
+        isolated.len()
+    }
+
+    /// Return the keys of vertices on the mesh boundary (naked vertices), sorted ascending.
+    pub fn naked_vertices(&self) -> Vec<usize> {
+        let mut out: Vec<usize> = self
+            .vertex
+            .keys()
+            .filter(|vk| self.is_vertex_on_boundary(**vk))
+            .copied()
+            .collect();
+        out.sort_unstable();
+        out
+    }
+
+    /// Return the boundary of the mesh as closed loops of vertex keys.
+    ///
+    /// Boundary halfedges (those without a face) are chained head to tail, so each loop
+    /// runs opposite to the winding of the faces along it. Loops start at their smallest
+    /// vertex key and are sorted by it. A closed mesh has no loops.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert_eq!(mesh.boundary_loops(), vec![vec![a, c, b]]);
+    /// ```
+    pub fn boundary_loops(&self) -> Vec<Vec<usize>> {
+        let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (&u, neigh) in &self.halfedge {
+            for (&v, face) in neigh {
+                if face.is_none() {
+                    outgoing.entry(u).or_default().push(v);
+                }
+            }
+        }
+        for targets in outgoing.values_mut() {
+            targets.sort_unstable_by(|a, b| b.cmp(a));
+        }
+
+        let mut starts: Vec<usize> = outgoing.keys().copied().collect();
+        starts.sort_unstable();
+        let mut loops = Vec::new();
+        for start in starts {
+            while let Some(mut v) = outgoing.get_mut(&start).and_then(|t| t.pop()) {
+                let mut cycle = vec![start];
+                while v != start {
+                    cycle.push(v);
+                    match outgoing.get_mut(&v).and_then(|t| t.pop()) {
+                        Some(next) => v = next,
+                        None => break,
+                    }
+                }
+                loops.push(cycle);
+            }
+        }
+        loops
+    }
+
+    /// Return the boundary loops of `boundary_loops` as vertex positions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Mesh;
+    /// let grid = Mesh::create_grid(2.0, 2.0, 2, 2);
+    /// let loops = grid.boundary_loops_points();
+    /// assert_eq!(loops.len(), 1);
+    /// assert_eq!(loops[0].len(), 8);
+    /// ```
+    pub fn boundary_loops_points(&self) -> Vec<Vec<Point>> {
+        self.boundary_loops()
+            .iter()
+            .map(|cycle| cycle.iter().filter_map(|vk| self.vertex_position(*vk)).collect())
+            .collect()
+    }
+
+    /// Flip every face whose normal points toward `reference`, so that all faces
+    /// point away from it. A face is flipped when the dot product of its normal with
+    /// the vector from `reference` to the face center is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 1.0), None);
+    /// let b = mesh.add_vertex(Point::new(0.0, 1.0, 1.0), None);
+    /// let c = mesh.add_vertex(Point::new(1.0, 0.0, 1.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// mesh.orient_faces_toward(&Point::new(0.0, 0.0, 0.0));
+    /// assert!(mesh.face_normal(f).unwrap().z > 0.0);
+    /// ```
+    pub fn orient_faces_toward(&mut self, reference: &Point) {
+        let mut flip: Vec<usize> = Vec::new();
+        for (fk, fverts) in &self.face {
+            let Some(normal) = self.face_normal(*fk) else { continue };
+            let points: Vec<Point> = fverts.iter().filter_map(|vk| self.vertex_position(*vk)).collect();
+            let Some(center) = Point::centroid(&points) else { continue };
+            let to_center = Vector::new(center.x - reference.x, center.y - reference.y, center.z - reference.z);
+            if normal.dot(&to_center) < 0.0 {
+                flip.push(*fk);
+            }
+        }
+        if flip.is_empty() {
+            return;
+        }
+        for fk in flip {
+            if let Some(fverts) = self.face.get_mut(&fk) {
+                fverts.reverse();
+            }
+            self.triangulation.remove(&fk);
+        }
+        self.rebuild_halfedges();
+    }
+
+    /// Reverse the vertex order of every face, flipping all face normals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    /// mesh.flip_normals();
+    /// assert_eq!(mesh.face_normal(f).unwrap().z, -1.0);
+    /// ```
+    pub fn flip_normals(&mut self) {
+        for fverts in self.face.values_mut() {
+            fverts.reverse();
+        }
+        self.triangulation.clear();
+        self.rebuild_halfedges();
+    }
+
+    /// Make the winding of edge-connected faces consistent.
+    ///
+    /// Each connected component is walked breadth-first from its smallest face key,
+    /// which keeps its orientation; a neighbor that traverses a shared edge in the
+    /// same direction is reversed. Adjacency is read from `self.face`, so meshes
+    /// whose halfedges are scrambled by inconsistent winding are handled too.
+    ///
+    /// # Returns
+    /// False if the mesh is non-orientable (e.g. a Möbius strip); the mesh is then
+    /// left unchanged.
+    pub fn unify_cycles(&mut self) -> bool {
+        let directed = |fverts: &[usize], u: usize, v: usize| {
+            let n = fverts.len();
+            (0..n).any(|i| fverts[i] == u && fverts[(i + 1) % n] == v)
+        };
+
+        let mut faces = self.face.clone();
+        let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (fk, fverts) in &faces {
+            let n = fverts.len();
+            for i in 0..n {
+                let (u, v) = (fverts[i], fverts[(i + 1) % n]);
+                edge_faces.entry((u.min(v), u.max(v))).or_default().push(*fk);
+            }
+        }
+
+        let mut fkeys: Vec<usize> = faces.keys().copied().collect();
+        fkeys.sort_unstable();
+        let mut visited: HashSet<usize> = HashSet::with_capacity(fkeys.len());
+        for start in fkeys {
+            if !visited.insert(start) {
+                continue;
+            }
+            let mut queue = std::collections::VecDeque::from([start]);
+            while let Some(fk) = queue.pop_front() {
+                let fverts = faces[&fk].clone();
+                let n = fverts.len();
+                for i in 0..n {
+                    let (u, v) = (fverts[i], fverts[(i + 1) % n]);
+                    for nb in &edge_faces[&(u.min(v), u.max(v))] {
+                        if *nb == fk {
+                            continue;
+                        }
+                        let same_direction = directed(&faces[nb], u, v);
+                        if visited.insert(*nb) {
+                            if same_direction {
+                                if let Some(nverts) = faces.get_mut(nb) {
+                                    nverts.reverse();
+                                }
+                            }
+                            queue.push_back(*nb);
+                        } else if same_direction {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.face = faces;
+        self.triangulation.clear();
+        self.rebuild_halfedges();
+        true
+    }
+
+    /// Merge pairs of adjacent, nearly coplanar triangles into quads.
+    ///
+    /// Candidate edges are shared by two triangles whose dihedral angle is at most
+    /// `angle_tol` (radians); they are processed from the flattest pair upwards and each
+    /// triangle is merged at most once. Pairs that would form a non-convex quad are
+    /// skipped. The quad keeps the key and attributes of the triangle on the (u, v)
+    /// halfedge of the shared edge.
+    ///
+    /// # Returns
+    /// The number of merged pairs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    /// let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// mesh.add_face(vec![a, c, d], None);
+    /// assert_eq!(mesh.merge_coplanar_triangles(0.01), 1);
+    /// assert_eq!(mesh.number_of_faces(), 1);
+    /// ```
+    pub fn merge_coplanar_triangles(&mut self, angle_tol: f32) -> usize {
+        self.merge_triangle_pairs(angle_tol, false)
+    }
+
+    /// Convert a triangle mesh into a quad-dominant mesh, returned as a new mesh.
+    ///
+    /// Pairs of adjacent triangles whose dihedral angle is at most `angle_tol` (radians)
+    /// are merged greedily, best pair first. A pair scores by how far the corners of the
+    /// resulting quad are from right angles plus its dihedral angle, so on a regular
+    /// triangulated grid the diagonals are removed first. Triangles without a partner,
+    /// non-convex pairs and other polygons are kept. Vertices are unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    /// let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// mesh.add_face(vec![a, c, d], None);
+    /// let quads = mesh.tri_to_quad(0.1);
+    /// assert_eq!(quads.number_of_faces(), 1);
+    /// assert_eq!(mesh.number_of_faces(), 2);
+    /// ```
+    pub fn tri_to_quad(&self, angle_tol: f32) -> Mesh {
+        let mut mesh = self.clone();
+        mesh.merge_triangle_pairs(angle_tol, true);
+        mesh
+    }
+
+    /// Greedily merge adjacent triangle pairs into convex quads, see
+    /// `merge_coplanar_triangles` and `tri_to_quad`. With `by_shape`, the quad corner
+    /// angles count towards the pair score next to the dihedral angle.
+    fn merge_triangle_pairs(&mut self, angle_tol: f32, by_shape: bool) -> usize {
+        // f1 runs u -> v -> a, f2 runs v -> u -> b
+        let opposite = |mesh: &Mesh, fk: usize, from: usize, to: usize| -> Option<usize> {
+            let fverts = mesh.face.get(&fk)?;
+            if fverts.len() != 3 {
+                return None;
+            }
+            let i = fverts.iter().position(|vk| *vk == from)?;
+            (fverts[(i + 1) % 3] == to).then(|| fverts[(i + 2) % 3])
+        };
+        let mut candidates: Vec<(f32, usize, usize, usize, usize, Vec<usize>)> = Vec::new();
+        for (u, v) in self.edges() {
+            let (f1, f2) = match self.edge_faces(u, v).as_slice() {
+                [f1, f2] => (*f1, *f2),
+                _ => continue,
+            };
+            let (Some(a), Some(b)) = (opposite(self, f1, u, v), opposite(self, f2, v, u)) else { continue };
+            let Some(angle) = self.dihedral_angle(u, v) else { continue };
+            if angle > angle_tol {
+                continue;
+            }
+            let quad = vec![v, a, u, b];
+            if !self.is_convex_polygon(&quad) {
+                continue;
+            }
+            let mut score = angle;
+            if by_shape {
+                score += self.polygon_corner_angles(&quad)
+                    .iter()
+                    .map(|corner| (corner - PI / 2.0).abs())
+                    .fold(0.0, f32::max);
+            }
+            candidates.push((score, u, v, f1, f2, quad));
+        }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+        let mut merged: HashSet<usize> = HashSet::new();
+        let mut count = 0;
+        for (_, u, v, f1, f2, quad) in candidates {
+            if merged.contains(&f1) || merged.contains(&f2) {
+                continue;
+            }
+            self.face.insert(f1, quad);
+            self.face.remove(&f2);
+            self.facedata.remove(&f2);
+            self.triangulation.remove(&f1);
+            self.triangulation.remove(&f2);
+            self.edgedata.remove(&(u, v));
+            self.edgedata.remove(&(v, u));
+            merged.insert(f1);
+            merged.insert(f2);
+            count += 1;
+        }
+        if count > 0 {
+            self.rebuild_halfedges();
+        }
+        count
+    }
+
+    /// Interior angles of a polygon given by vertex keys, see `face_angles`.
+    fn polygon_corner_angles(&self, vertices: &[usize]) -> Vec<f32> {
+        let points: Vec<Point> = vertices.iter().filter_map(|vk| self.vertex_position(*vk)).collect();
+        let n = points.len();
+        (0..n)
+            .map(|i| {
+                let p = points[i];
+                let prev = points[(i + n - 1) % n];
+                let next = points[(i + 1) % n];
+                let e1 = Vector::new(prev.x - p.x, prev.y - p.y, prev.z - p.z);
+                let e2 = Vector::new(next.x - p.x, next.y - p.y, next.z - p.z);
+                e1.angle(&e2)
+            })
+            .collect()
+    }
+
+    /// Check that a polygon is strictly convex: every corner turns the same way as the
+    /// polygon normal.
+    fn is_convex_polygon(&self, vertices: &[usize]) -> bool {
+        let points: Vec<Point> = vertices.iter().filter_map(|vk| self.vertex_position(*vk)).collect();
+        if points.len() != vertices.len() || points.len() < 3 {
+            return false;
+        }
+        let n = points.len();
+        let edge = |i: usize| {
+            let p = &points[i];
+            let q = &points[(i + 1) % n];
+            Vector::new(q.x - p.x, q.y - p.y, q.z - p.z)
+        };
+        let corners: Vec<Vector> = (0..n).map(|i| edge(i).cross(&edge((i + 1) % n))).collect();
+        let mut normal = Vector::new(0.0, 0.0, 0.0);
+        for c in &corners {
+            normal.x += c.x;
+            normal.y += c.y;
+            normal.z += c.z;
+        }
+        corners.iter().all(|c| c.dot(&normal) > 0.0)
+    }
+
+    /// Build a closed shell from an open surface with per-vertex thickness.
+    ///
+    /// Every vertex is offset along its normal by `thickness[vertex]` (or `default`).
+    /// The offset copy keeps the face orientation, the original surface is reversed,
+    /// and each boundary edge is stitched with a quad, so a consistently oriented
+    /// surface gives a closed, outward-facing solid. Isolated vertices are dropped.
+    ///
+    /// # Arguments
+    /// * `thickness` - Offset distance per vertex key of this mesh
+    /// * `default` - Offset distance for vertices missing from `thickness`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Mesh;
+    /// use std::collections::HashMap;
+    /// let grid = Mesh::create_grid(1.0, 1.0, 2, 2);
+    /// let shell = grid.shell(&HashMap::new(), 0.1);
+    /// assert_eq!(shell.number_of_vertices(), 18);
+    /// assert!(shell.naked_vertices().is_empty());
+    /// ```
+    pub fn shell(&self, thickness: &HashMap<usize, f32>, default: f32) -> Mesh {
+        let mut vkeys: Vec<usize> = self.face.values().flatten().copied().collect();
+        vkeys.sort_unstable();
+        vkeys.dedup();
+
+        let mut shell = Mesh::with_capacity(2 * vkeys.len(), 2 * self.face.len());
+        let mut inner: HashMap<usize, usize> = HashMap::with_capacity(vkeys.len());
+        let mut outer: HashMap<usize, usize> = HashMap::with_capacity(vkeys.len());
+        for vk in &vkeys {
+            let Some(p) = self.vertex_position(*vk) else { continue };
+            let n = self.vertex_normal(*vk).unwrap_or(Vector::new(0.0, 0.0, 0.0));
+            let t = thickness.get(vk).copied().unwrap_or(default);
+            let offset = Point::new(p.x + n.x * t, p.y + n.y * t, p.z + n.z * t);
+            inner.insert(*vk, shell.add_vertex(p, None));
+            outer.insert(*vk, shell.add_vertex(offset, None));
+        }
+
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        for fk in &fkeys {
+            let fverts = &self.face[fk];
+            shell.add_face(fverts.iter().map(|vk| outer[vk]).collect(), None);
+            shell.add_face(fverts.iter().rev().map(|vk| inner[vk]).collect(), None);
+        }
+
+        // Boundary halfedges run u -> v along a face with no face on v -> u
+        let mut boundary: Vec<(usize, usize)> = Vec::new();
+        for (u, nbrs) in &self.halfedge {
+            for (v, face) in nbrs {
+                let opposite = self.halfedge.get(v).and_then(|n| n.get(u)).copied().flatten();
+                if face.is_some() && opposite.is_none() {
+                    boundary.push((*u, *v));
+                }
+            }
+        }
+        boundary.sort_unstable();
+        for (u, v) in boundary {
+            shell.add_face(vec![inner[&u], inner[&v], outer[&v], outer[&u]], None);
+        }
+        shell
+    }
+
+    /// Laplacian smoothing: each iteration moves every interior vertex by `lambda` towards
+    /// the average of its `vertex_neighbors`. Boundary vertices (`is_vertex_on_boundary`)
+    /// and isolated vertices stay fixed.
+    ///
+    /// All vertices move simultaneously: each iteration reads the positions from
+    /// before it started. `lambda` is usually in `(0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut grid = Mesh::create_grid(2.0, 2.0, 2, 2);
+    /// let center = grid.grid_vertex_key(1, 1).unwrap();
+    /// grid.vertex.get_mut(&center).unwrap().z = 1.0;
+    /// grid.smooth_laplacian(1, 0.5);
+    /// assert_eq!(grid.vertex_position(center).unwrap().z, 0.5);
+    /// ```
+    pub fn smooth_laplacian(&mut self, iterations: usize, lambda: f32) {
+        let fixed: HashSet<usize> = self.naked_vertices().into_iter().collect();
+        let movable: Vec<(usize, Vec<usize>)> = self
+            .vertex
+            .keys()
+            .filter(|vk| !fixed.contains(vk))
+            .map(|vk| (*vk, self.vertex_neighbors(*vk)))
+            .filter(|(_, neighbors)| !neighbors.is_empty())
+            .collect();
+
+        for _ in 0..iterations {
+            let positions: HashMap<usize, Point> = self.vertex.iter().map(|(k, v)| (*k, v.position())).collect();
+            for (vk, neighbors) in &movable {
+                let p = positions[vk];
+                let neighbor_positions: Vec<Point> = neighbors.iter().map(|nb| positions[nb]).collect();
+                let Some(average) = Point::centroid(&neighbor_positions) else { continue };
+                let moved = p.lerp(&average, lambda);
+                if let Some(v) = self.vertex.get_mut(vk) {
+                    v.set_position(moved);
+                }
+            }
+        }
+        self.triangulation.clear();
+    }
+
+    /// Subdivide the mesh `iterations` times with the given scheme.
+    ///
+    /// `Linear` and `CatmullClark` accept any polygon faces and output quads. `Loop`
+    /// needs triangles: other faces are triangulated before the first iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// use openmodel::geometry::mesh::SubdivScheme;
+    /// let mut quad = Mesh::new();
+    /// let a = quad.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = quad.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = quad.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    /// let d = quad.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// quad.add_face(vec![a, b, c, d], None);
+    /// let fine = quad.subdivide(SubdivScheme::Linear, 2);
+    /// assert_eq!(fine.number_of_faces(), 16);
+    /// ```
+    #[must_use]
+    pub fn subdivide(&self, scheme: SubdivScheme, iterations: usize) -> Mesh {
+        match scheme {
+            SubdivScheme::Linear => self.subdivide_linear(iterations),
+            SubdivScheme::CatmullClark => self.subdivide_catmull_clark(iterations),
+            SubdivScheme::Loop => self.subdivide_loop(iterations),
+        }
+    }
+
+    /// Midpoint subdivision: every n-gon becomes n quads joining its corners, edge
+    /// midpoints and center. Positions are not smoothed.
+    #[must_use]
+    pub fn subdivide_linear(&self, iterations: usize) -> Mesh {
+        let mut mesh = self.clone();
+        for _ in 0..iterations {
+            mesh = mesh.subdivide_quad_step(false);
+        }
+        mesh
+    }
+
+    /// Catmull-Clark subdivision for arbitrary polygon meshes. Each iteration replaces
+    /// every n-gon by n quads; boundary edges and vertices follow the cubic B-spline
+    /// boundary rules.
+    ///
+    /// Edges tagged with a crease weight (see `set_edge_crease`) blend between the smooth
+    /// and the sharp rules: a vertex on two creased edges follows the crease curve, a
+    /// vertex on three or more stays put as a corner. Both halves of a split edge keep
+    /// its weight, so a weight of 1 stays fully sharp across all iterations.
+    #[must_use]
+    pub fn subdivide_catmull_clark(&self, iterations: usize) -> Mesh {
+        let mut mesh = self.clone();
+        for _ in 0..iterations {
+            mesh = mesh.subdivide_quad_step(true);
+        }
+        mesh
+    }
+
+    /// Loop subdivision for triangle meshes. Each iteration splits every triangle into
+    /// four; non-triangular faces are triangulated first.
+    #[must_use]
+    pub fn subdivide_loop(&self, iterations: usize) -> Mesh {
+        if iterations == 0 {
+            return self.clone();
+        }
+        let mut mesh = self.clone();
+        if mesh.face.values().any(|fverts| fverts.len() != 3) {
+            mesh = mesh.to_triangle_mesh();
+        }
+        for _ in 0..iterations {
+            mesh = mesh.subdivide_loop_step();
+        }
+        mesh
+    }
+
+    /// Copy of the mesh with every face split into triangles, see `triangulate`.
+    fn to_triangle_mesh(&self) -> Mesh {
+        let mut out = self.clone();
+        out.triangulate();
+        out
+    }
+
+    /// Split every face with 4 or more vertices into triangles.
+    ///
+    /// Faces are triangulated like `triangulate_face_vertices`: ear clipping, which
+    /// gives a fan-like split for convex faces and also handles concave ones. The first
+    /// triangle keeps the face key, the others get new keys, and every triangle gets a
+    /// copy of the source face attributes. Halfedges are rebuilt afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    /// let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c, d], None);
+    /// mesh.triangulate();
+    /// assert_eq!(mesh.number_of_faces(), 2);
+    /// assert_eq!(mesh.number_of_edges(), 5);
+    /// ```
+    pub fn triangulate(&mut self) {
+        let mut fkeys: Vec<usize> = self.face.iter().filter(|(_, fverts)| fverts.len() > 3).map(|(fk, _)| *fk).collect();
+        if fkeys.is_empty() {
+            return;
+        }
+        fkeys.sort_unstable();
+        for fk in fkeys {
+            let triangles = self.triangulate_face_vertices(&self.face[&fk]);
+            let attrs = self.facedata.get(&fk).cloned();
+            for (i, tri) in triangles.into_iter().enumerate() {
+                let key = if i == 0 { fk } else { self.reserve_face_key() };
+                self.face.insert(key, tri.to_vec());
+                if let Some(attrs) = &attrs {
+                    self.facedata.insert(key, attrs.clone());
+                }
+            }
+        }
+        self.triangulation.clear();
+        self.rebuild_halfedges();
+    }
+
+    /// Dual mesh: a vertex at the `face_centroid` of every face, and a face for every
+    /// interior vertex joining the centroids of its surrounding faces.
+    ///
+    /// Dual faces follow the faces around the vertex in the winding of the primal
+    /// mesh, so a consistently oriented mesh gives a dual with matching normals.
+    /// Boundary vertices are skipped. The result is plain geometry, attributes are not copied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Mesh;
+    /// let grid = Mesh::create_grid(3.0, 3.0, 3, 3);
+    /// let dual = grid.dual();
+    /// assert_eq!(dual.number_of_vertices(), 9);
+    /// assert_eq!(dual.number_of_faces(), 4);
+    /// ```
+    pub fn dual(&self) -> Mesh {
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        let mut vkeys: Vec<usize> = self.vertex.keys().copied().collect();
+        vkeys.sort_unstable();
+
+        let mut dual = Mesh::with_capacity(fkeys.len(), vkeys.len());
+        dual.data = self.data.copy(false);
+        let mut face_vertex: HashMap<usize, usize> = HashMap::with_capacity(fkeys.len());
+        for fk in &fkeys {
+            if let Some(centroid) = self.face_centroid(*fk) {
+                face_vertex.insert(*fk, dual.add_vertex(centroid, None));
+            }
+        }
+
+        for vk in vkeys {
+            if self.is_vertex_on_boundary(vk) {
+                continue;
+            }
+            let Some(&start) = self.vertex_neighbors(vk).first() else { continue };
+            // Rotate around the vertex: from halfedge (vk, w) in face f, the previous
+            // halfedge of f ends in vk and its twin starts the next face
+            let mut cycle = Vec::new();
+            let mut w = start;
+            while let Some(fk) = self.halfedge_face(vk, w) {
+                cycle.push(fk);
+                let Some((x, _)) = self.halfedge_prev(vk, w) else { break };
+                w = x;
+                if w == start || cycle.len() > self.face.len() {
+                    break;
+                }
+            }
+            if w != start || cycle.len() < 3 {
+                continue;
+            }
+            let keys: Option<Vec<usize>> = cycle.iter().map(|fk| face_vertex.get(fk).copied()).collect();
+            if let Some(keys) = keys {
+                dual.add_face(keys, None);
+            }
+        }
+        dual
+    }
+
+    /// Crease weight of the edge `(u, v)` in either direction, read from the `"crease"`
+    /// edge attribute and clamped to `0..=1`. Untagged edges return 0 (smooth).
+    pub fn edge_crease(&self, u: usize, v: usize) -> f32 {
+        self.edgedata
+            .get(&(u, v))
+            .or_else(|| self.edgedata.get(&(v, u)))
+            .and_then(|attrs| attrs.get("crease"))
+            .map_or(0.0, |w| w.clamp(0.0, 1.0))
+    }
+
+    /// Tag the edge `(u, v)` with a crease weight in `0..=1` for Catmull-Clark
+    /// subdivision: 0 is smooth, 1 is fully sharp.
+    ///
+    /// # Returns
+    /// False if `(u, v)` is not an edge of the mesh.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert!(mesh.set_edge_crease(b, a, 1.0));
+    /// assert_eq!(mesh.edge_crease(a, b), 1.0);
+    /// ```
+    pub fn set_edge_crease(&mut self, u: usize, v: usize, weight: f32) -> bool {
+        if !self.halfedge.get(&u).is_some_and(|nbrs| nbrs.contains_key(&v)) {
+            return false;
+        }
+        let key = if self.edgedata.contains_key(&(v, u)) { (v, u) } else { (u, v) };
+        self.edgedata
+            .entry(key)
+            .or_default()
+            .insert("crease".to_string(), weight.clamp(0.0, 1.0));
+        true
+    }
+
+    /// One step of quad subdivision; `smooth` applies the Catmull-Clark rules.
+    fn subdivide_quad_step(&self, smooth: bool) -> Mesh {
+        let mut vkeys: Vec<usize> = self.vertex.keys().copied().collect();
+        vkeys.sort_unstable();
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        let edges = self.edges();
+
+        let position = |vk: usize| self.vertex_position(vk).unwrap_or_default();
+        let average = |points: &[Point]| Point::centroid(points).unwrap_or_default();
+
+        let face_points: HashMap<usize, Point> = fkeys
+            .iter()
+            .map(|fk| {
+                let points: Vec<Point> = self.face[fk].iter().map(|vk| position(*vk)).collect();
+                (*fk, average(&points))
+            })
+            .collect();
+
+        let mut edge_points: HashMap<(usize, usize), Point> = HashMap::with_capacity(edges.len());
+        for &(u, v) in &edges {
+            let faces = self.edge_faces(u, v);
+            let midpoint = average(&[position(u), position(v)]);
+            let point = if smooth && faces.len() == 2 {
+                let smooth_point = average(&[position(u), position(v), face_points[&faces[0]], face_points[&faces[1]]]);
+                smooth_point.lerp(&midpoint, self.edge_crease(u, v))
+            } else {
+                midpoint
+            };
+            edge_points.insert((u, v), point);
+        }
+
+        let mut mesh = Mesh::with_capacity(vkeys.len() + edges.len() + fkeys.len(), 4 * fkeys.len());
+        mesh.data = self.data.copy(false);
+        let mut vertex_map: HashMap<usize, usize> = HashMap::with_capacity(vkeys.len());
+        for &vk in &vkeys {
+            let p = position(vk);
+            let point = if smooth { self.catmull_clark_vertex_point(vk, &face_points) } else { p };
+            vertex_map.insert(vk, mesh.add_vertex(point, None));
+        }
+        let edge_map: HashMap<(usize, usize), usize> = edges
+            .iter()
+            .map(|e| (*e, mesh.add_vertex(edge_points[e], None)))
+            .collect();
+        let edge_key = |a: usize, b: usize| edge_map[&(a.min(b), a.max(b))];
+
+        for fk in &fkeys {
+            let center = mesh.add_vertex(face_points[fk], None);
+            let fverts = &self.face[fk];
+            let n = fverts.len();
+            for i in 0..n {
+                let prev = fverts[(i + n - 1) % n];
+                let curr = fverts[i];
+                let next = fverts[(i + 1) % n];
+                let new_face = mesh.add_face(vec![vertex_map[&curr], edge_key(curr, next), center, edge_key(prev, curr)], None);
+                if let (Some(new_face), Some(attrs)) = (new_face, self.facedata.get(fk)) {
+                    mesh.facedata.insert(new_face, attrs.clone());
+                }
+            }
+        }
+
+        // Both halves of a creased edge inherit its weight
+        for &(u, v) in &edges {
+            let weight = self.edge_crease(u, v);
+            if weight > 0.0 {
+                let mid = edge_key(u, v);
+                mesh.set_edge_crease(vertex_map[&u], mid, weight);
+                mesh.set_edge_crease(mid, vertex_map[&v], weight);
+            }
+        }
+        mesh
+    }
+
+    /// Catmull-Clark position of an existing vertex.
+    fn catmull_clark_vertex_point(&self, vk: usize, face_points: &HashMap<usize, Point>) -> Point {
+        let p = self.vertex_position(vk).unwrap_or_default();
+        let neighbors = self.vertex_neighbors(vk);
+        let boundary: Vec<usize> = neighbors.iter().copied().filter(|nb| self.is_edge_on_boundary(vk, *nb)).collect();
+        if !boundary.is_empty() {
+            // Boundary rule: (a + 6p + b) / 8, corners and non-manifold vertices stay put
+            if boundary.len() != 2 {
+                return p;
+            }
+            let a = self.vertex_position(boundary[0]).unwrap_or(p);
+            let b = self.vertex_position(boundary[1]).unwrap_or(p);
+            return Point::new(
+                (a.x + 6.0 * p.x + b.x) / 8.0,
+                (a.y + 6.0 * p.y + b.y) / 8.0,
+                (a.z + 6.0 * p.z + b.z) / 8.0,
+            );
+        }
+
+        // Creased edges: two make a crease curve, three or more pin the vertex as a corner
+        let creased: Vec<(usize, f32)> = neighbors
+            .iter()
+            .map(|nb| (*nb, self.edge_crease(vk, *nb)))
+            .filter(|(_, w)| *w > 0.0)
+            .collect();
+        let smooth_point = self.catmull_clark_interior_point(vk, &neighbors, face_points);
+        if creased.len() < 2 {
+            return smooth_point;
+        }
+        let sharp_point = if creased.len() == 2 {
+            let a = self.vertex_position(creased[0].0).unwrap_or(p);
+            let b = self.vertex_position(creased[1].0).unwrap_or(p);
+            Point::new(
+                (a.x + 6.0 * p.x + b.x) / 8.0,
+                (a.y + 6.0 * p.y + b.y) / 8.0,
+                (a.z + 6.0 * p.z + b.z) / 8.0,
+            )
+        } else {
+            p
+        };
+        let weight = creased.iter().map(|(_, w)| w).sum::<f32>() / creased.len() as f32;
+        smooth_point.lerp(&sharp_point, weight)
+    }
+
+    /// Catmull-Clark position of an interior vertex without creases.
+    fn catmull_clark_interior_point(&self, vk: usize, neighbors: &[usize], face_points: &HashMap<usize, Point>) -> Point {
+        // Interior rule: (Q + 2R + (n - 3)P) / n
+        let p = self.vertex_position(vk).unwrap_or_default();
+        let faces = self.vertex_faces(vk);
+        let n = neighbors.len() as f32;
+        if faces.is_empty() || neighbors.is_empty() {
+            return p;
+        }
+        let face_centers: Vec<Point> = faces.iter().map(|fk| face_points[fk]).collect();
+        let edge_midpoints: Vec<Point> = neighbors
+            .iter()
+            .map(|nb| p.midpoint(&self.vertex_position(*nb).unwrap_or(p)))
+            .collect();
+        let q = Point::centroid(&face_centers).unwrap_or(p);
+        let r = Point::centroid(&edge_midpoints).unwrap_or(p);
+        Point::new(
+            (q.x + 2.0 * r.x + (n - 3.0) * p.x) / n,
+            (q.y + 2.0 * r.y + (n - 3.0) * p.y) / n,
+            (q.z + 2.0 * r.z + (n - 3.0) * p.z) / n,
+        )
+    }
+
+    /// One step of Loop subdivision on a triangle mesh.
+    fn subdivide_loop_step(&self) -> Mesh {
+        let mut vkeys: Vec<usize> = self.vertex.keys().copied().collect();
+        vkeys.sort_unstable();
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        let edges = self.edges();
+        let position = |vk: usize| self.vertex_position(vk).unwrap_or_default();
+        let weighted = |terms: &[(f32, Point)]| {
+            terms.iter().fold(Point::new(0.0, 0.0, 0.0), |acc, (w, p)| {
+                Point::new(acc.x + w * p.x, acc.y + w * p.y, acc.z + w * p.z)
+            })
+        };
+        // Vertex of triangle `fk` that is not on edge (u, v)
+        let opposite = |fk: usize, u: usize, v: usize| {
+            self.face[&fk].iter().copied().find(|vk| *vk != u && *vk != v)
+        };
+
+        let mut mesh = Mesh::with_capacity(vkeys.len() + edges.len(), 4 * fkeys.len());
+        mesh.data = self.data.copy(false);
+        let mut vertex_map: HashMap<usize, usize> = HashMap::with_capacity(vkeys.len());
+        for &vk in &vkeys {
+            let p = position(vk);
+            let neighbors = self.vertex_neighbors(vk);
+            let boundary: Vec<usize> = neighbors.iter().copied().filter(|nb| self.is_edge_on_boundary(vk, *nb)).collect();
+            let point = if !boundary.is_empty() {
+                if boundary.len() == 2 {
+                    weighted(&[(0.75, p), (0.125, position(boundary[0])), (0.125, position(boundary[1]))])
+                } else {
+                    p
+                }
+            } else if neighbors.is_empty() {
+                p
+            } else {
+                let n = neighbors.len() as f32;
+                let beta = if neighbors.len() == 3 { 3.0 / 16.0 } else { 3.0 / (8.0 * n) };
+                let mut terms: Vec<(f32, Point)> = neighbors.iter().map(|nb| (beta, position(*nb))).collect();
+                terms.push((1.0 - n * beta, p));
+                weighted(&terms)
+            };
+            vertex_map.insert(vk, mesh.add_vertex(point, None));
+        }
+
+        let mut edge_map: HashMap<(usize, usize), usize> = HashMap::with_capacity(edges.len());
+        for &(u, v) in &edges {
+            let faces = self.edge_faces(u, v);
+            let opposites: Vec<usize> = faces.iter().filter_map(|fk| opposite(*fk, u, v)).collect();
+            let point = if opposites.len() == 2 {
+                weighted(&[
+                    (0.375, position(u)),
+                    (0.375, position(v)),
+                    (0.125, position(opposites[0])),
+                    (0.125, position(opposites[1])),
+                ])
+            } else {
+                weighted(&[(0.5, position(u)), (0.5, position(v))])
+            };
+            edge_map.insert((u, v), mesh.add_vertex(point, None));
+        }
+        let edge_key = |a: usize, b: usize| edge_map[&(a.min(b), a.max(b))];
+
+        for fk in &fkeys {
+            let (a, b, c) = match self.face[fk].as_slice() {
+                [a, b, c] => (*a, *b, *c),
+                _ => continue,
+            };
+            let (ab, bc, ca) = (edge_key(a, b), edge_key(b, c), edge_key(c, a));
+            for tri in [
+                vec![vertex_map[&a], ab, ca],
+                vec![vertex_map[&b], bc, ab],
+                vec![vertex_map[&c], ca, bc],
+                vec![ab, bc, ca],
+            ] {
+                let new_face = mesh.add_face(tri, None);
+                if let (Some(new_face), Some(attrs)) = (new_face, self.facedata.get(fk)) {
+                    mesh.facedata.insert(new_face, attrs.clone());
+                }
+            }
+        }
+        mesh
+    }
+
+    /// Rebuild the halfedge connectivity from the face vertex lists.
+    /// Every vertex keeps an entry, isolated vertices an empty one.
+    fn rebuild_halfedges(&mut self) {
+        self.halfedge.clear();
+        for vk in self.vertex.keys() {
+            self.halfedge.insert(*vk, HashMap::new());
+        }
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        for fk in fkeys {
+            let fverts = &self.face[&fk];
+            for i in 0..fverts.len() {
+                let u = fverts[i];
+                let v = fverts[(i + 1) % fverts.len()];
+                self.halfedge.entry(u).or_default().insert(v, Some(fk));
+                self.halfedge.entry(v).or_default().entry(u).or_insert(None);
+            }
+        }
+    }
+
+    /// Extract all unique edges of the mesh as Line objects.
+    /// This includes both boundary and interior edges.
+    pub fn extract_edges_as_lines(&self) -> Vec<Line> {
         let mut out: Vec<Line> = Vec::new();
         let mut seen: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
 
@@ -961,6 +3076,31 @@ impl Mesh {
         out
     }
 
+    /// Convert the mesh edges to a `LineCloud`, one line per undirected edge
+    /// (in `edges` order), all with the given color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Color};
+    /// let grid = Mesh::create_grid(1.0, 1.0, 1, 1);
+    /// let wire = grid.to_wireframe(Color::black());
+    /// assert_eq!(wire.lines.len(), 4);
+    /// ```
+    pub fn to_wireframe(&self, color: Color) -> LineCloud {
+        let lines: Vec<Line> = self
+            .edges()
+            .into_iter()
+            .filter_map(|(u, v)| {
+                let p0 = self.vertex_position(u)?;
+                let p1 = self.vertex_position(v)?;
+                Some(Line::from_points(&p0, &p1))
+            })
+            .collect();
+        let colors = vec![color; lines.len()];
+        LineCloud::new(lines, colors)
+    }
+
     /// Extract transforms for pipes along unique boundary edges of the mesh.
     /// Uses the canonical unit pipe definition (aligned +Z, length=1, radius=0.5).
     pub fn extract_edge_pipe_transforms(&self) -> Vec<Xform> {
@@ -1063,40 +3203,50 @@ impl Mesh {
 
     /// Create a unit sphere with specified subdivision levels.
     pub fn create_unit_sphere_subdivisions(subdiv: usize) -> Self {
-        // Build an icosphere in local space, then load into Mesh.
-        let radius: f32 = 0.5;
-        let _subdiv_param = subdiv;
+        Self::create_icosphere(Point::new(0.0, 0.0, 0.0), 0.5, subdiv)
+    }
 
-        // Initial icosahedron vertices
+    /// Create an icosphere: an icosahedron whose triangles are subdivided `subdivisions`
+    /// times, with new vertices projected onto the sphere. Edge midpoints are shared
+    /// between neighboring triangles, so the result is a closed, welded mesh with
+    /// outward-facing triangles.
+    ///
+    /// # Arguments
+    /// * `center` - Center of the sphere
+    /// * `radius` - Radius of the sphere
+    /// * `subdivisions` - Number of 1-to-4 triangle subdivisions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let sphere = Mesh::create_icosphere(Point::new(0.0, 0.0, 0.0), 1.0, 1);
+    /// assert_eq!(sphere.number_of_vertices(), 42);
+    /// assert_eq!(sphere.number_of_faces(), 80);
+    /// ```
+    pub fn create_icosphere(center: Point, radius: f32, subdivisions: usize) -> Self {
+        // Initial icosahedron vertices on the unit sphere
         let t = (1.0 + 5.0_f32.sqrt()) / 2.0;
-        let mut pts: Vec<Point> = vec![
-            Point::new(-1.0,                        t,    0.0),
-            Point::new( 1.0,                        t,    0.0),
-            Point::new(-1.0,                       -t,    0.0),
-            Point::new( 1.0,                       -t,    0.0),
-            Point::new( 0.0,                       -1.0,  t  ),
-            Point::new( 0.0,                        1.0,  t  ),
-            Point::new( 0.0,                       -1.0, -t  ),
-            Point::new( 0.0,                        1.0, -t  ),
-            Point::new( t,                         0.0, -1.0),
-            Point::new( t,                         0.0,  1.0),
-            Point::new(-t,                         0.0, -1.0),
-            Point::new(-t,                         0.0,  1.0),
-            Point::new( 0.0,  1.0, -t  ),
-            Point::new(  t,   0.0, -1.0),
-            Point::new(  t,   0.0,  1.0),
-            Point::new( -t,   0.0, -1.0),
-            Point::new( -t,   0.0,  1.0),
-        ];
-
-        // Normalize to radius
-        let norm_to_r = |p: &Point, r: f32| -> Point {
-            let len = (p.x*p.x + p.y*p.y + p.z*p.z).sqrt();
-            if len > 0.0 { Point::new(p.x/len*r, p.y/len*r, p.z/len*r) } else { Point::new(0.0, 0.0, r) }
+        let unit = |x: f32, y: f32, z: f32| -> [f32; 3] {
+            let len = (x * x + y * y + z * z).sqrt();
+            [x / len, y / len, z / len]
         };
-        for p in &mut pts { *p = norm_to_r(p, radius); }
+        let mut pts: Vec<[f32; 3]> = vec![
+            unit(-1.0, t, 0.0),
+            unit(1.0, t, 0.0),
+            unit(-1.0, -t, 0.0),
+            unit(1.0, -t, 0.0),
+            unit(0.0, -1.0, t),
+            unit(0.0, 1.0, t),
+            unit(0.0, -1.0, -t),
+            unit(0.0, 1.0, -t),
+            unit(t, 0.0, -1.0),
+            unit(t, 0.0, 1.0),
+            unit(-t, 0.0, -1.0),
+            unit(-t, 0.0, 1.0),
+        ];
 
-        // Base icosahedron faces
+        // Base icosahedron faces, counter-clockwise seen from outside
         let mut faces: Vec<[usize; 3]> = vec![
             [0, 11, 5],  [0, 5, 1],   [0, 1, 7],   [0, 7, 10],  [0, 10, 11],
             [1, 5, 9],   [5, 11, 4],  [11, 10, 2], [10, 7, 6],  [7, 1, 8],
@@ -1104,30 +3254,26 @@ impl Mesh {
             [4, 9, 5],   [2, 4, 11],  [6, 2, 10],  [8, 6, 7],   [9, 8, 1],
         ];
 
-        // Subdivision helper: edge midpoint cache
-        use std::collections::HashMap;
-        
-        for _ in 0.._subdiv_param {
-            let mut new_faces: Vec<[usize; 3]> = Vec::with_capacity(faces.len()*4);
+        for _ in 0..subdivisions {
+            let mut new_faces: Vec<[usize; 3]> = Vec::with_capacity(faces.len() * 4);
             let mut midpoint_cache: HashMap<(usize, usize), usize> = HashMap::new();
-            
-            let get_midpoint = |a: usize, b: usize, pts: &mut Vec<Point>, cache: &mut HashMap<(usize, usize), usize>| -> usize {
+
+            let mut midpoint = |a: usize, b: usize, pts: &mut Vec<[f32; 3]>| -> usize {
                 let key = if a < b { (a, b) } else { (b, a) };
-                if let Some(&idx) = cache.get(&key) { return idx; }
-                let pa = &pts[a];
-                let pb = &pts[b];
-                let pm = Point::new((pa.x + pb.x)*0.5, (pa.y + pb.y)*0.5, (pa.z + pb.z)*0.5);
-                let pm = norm_to_r(&pm, radius);
+                if let Some(&idx) = midpoint_cache.get(&key) {
+                    return idx;
+                }
+                let (pa, pb) = (pts[a], pts[b]);
                 let idx = pts.len();
-                pts.push(pm);
-                cache.insert(key, idx);
+                pts.push(unit(pa[0] + pb[0], pa[1] + pb[1], pa[2] + pb[2]));
+                midpoint_cache.insert(key, idx);
                 idx
             };
-            
+
             for [i, j, k] in faces.iter().copied() {
-                let a = get_midpoint(i, j, &mut pts, &mut midpoint_cache);
-                let b = get_midpoint(j, k, &mut pts, &mut midpoint_cache);
-                let c = get_midpoint(k, i, &mut pts, &mut midpoint_cache);
+                let a = midpoint(i, j, &mut pts);
+                let b = midpoint(j, k, &mut pts);
+                let c = midpoint(k, i, &mut pts);
                 new_faces.push([i, a, c]);
                 new_faces.push([j, b, a]);
                 new_faces.push([k, c, b]);
@@ -1136,40 +3282,96 @@ impl Mesh {
             faces = new_faces;
         }
 
-        // Build Mesh from points and faces
-        let mut m = Mesh::new();
-        let mut vmap: Vec<usize> = Vec::with_capacity(pts.len());
-        for p in pts.into_iter() {
-            let vk = m.add_vertex(p, None);
-            vmap.push(vk);
-        }
-        // Enforce consistent outward winding per triangle
+        let mut mesh = Mesh::new();
+        let vkeys: Vec<usize> = pts
+            .iter()
+            .map(|p| {
+                mesh.add_vertex(
+                    Point::new(center.x + p[0] * radius, center.y + p[1] * radius, center.z + p[2] * radius),
+                    None,
+                )
+            })
+            .collect();
         for [a, b, c] in faces {
-            let pa = m.vertex_position(vmap[a]).unwrap();
-            let pb = m.vertex_position(vmap[b]).unwrap();
-            let pc = m.vertex_position(vmap[c]).unwrap();
-            let ux = pb.x - pa.x; let uy = pb.y - pa.y; let uz = pb.z - pa.z;
-            let vx = pc.x - pa.x; let vy = pc.y - pa.y; let vz = pc.z - pa.z;
-            let nx = uy * vz - uz * vy;
-            let ny = uz * vx - ux * vz;
-            let nz = ux * vy - uy * vx;
-            // Outward if normal roughly agrees with radial direction (use pa)
-            let dot = nx * pa.x + ny * pa.y + nz * pa.z;
-            if dot >= 0.0 {
-                let _ = m.add_face(vec![vmap[a], vmap[b], vmap[c]], None);
-            } else {
-                let _ = m.add_face(vec![vmap[a], vmap[c], vmap[b]], None);
+            mesh.add_face(vec![vkeys[a], vkeys[b], vkeys[c]], None);
+        }
+        mesh
+    }
+
+    /// Create a regular quad grid in the XY plane, starting at the origin.
+    ///
+    /// The grid has `x_count` by `y_count` quad faces and `(x_count + 1) * (y_count + 1)`
+    /// vertices, keyed row by row from the origin. The face counts are stored in the
+    /// `grid_x_count` and `grid_y_count` mesh attributes for `grid_vertex_key`.
+    ///
+    /// # Arguments
+    /// * `x_size` - Total size along X
+    /// * `y_size` - Total size along Y
+    /// * `x_count` - Number of faces along X
+    /// * `y_count` - Number of faces along Y
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Mesh;
+    /// let grid = Mesh::create_grid(2.0, 1.0, 4, 2);
+    /// assert_eq!(grid.number_of_vertices(), 15);
+    /// assert_eq!(grid.number_of_faces(), 8);
+    /// ```
+    pub fn create_grid(x_size: f32, y_size: f32, x_count: usize, y_count: usize) -> Self {
+        let mut mesh = Mesh::new();
+        if x_count == 0 || y_count == 0 {
+            return mesh;
+        }
+        let dx = x_size / x_count as f32;
+        let dy = y_size / y_count as f32;
+
+        mesh.attributes.insert("grid_x_count".to_string(), x_count as f32);
+        mesh.attributes.insert("grid_y_count".to_string(), y_count as f32);
+
+        let row = x_count + 1;
+        for j in 0..=y_count {
+            for i in 0..=x_count {
+                mesh.add_vertex(Point::new(i as f32 * dx, j as f32 * dy, 0.0), Some(j * row + i));
             }
         }
 
-        m
+        for j in 0..y_count {
+            for i in 0..x_count {
+                let a = j * row + i;
+                let d = a + row;
+                mesh.add_face(vec![a, a + 1, d + 1, d], None);
+            }
+        }
+        mesh
+    }
+
+    /// Look up the vertex created at grid coordinate `(i, j)` by `create_grid`.
+    ///
+    /// # Returns
+    /// The vertex key, or None if the mesh has no grid dimensions, `(i, j)` is outside
+    /// the grid or the vertex has been removed.
+    pub fn grid_vertex_key(&self, i: usize, j: usize) -> Option<usize> {
+        let x_count = *self.attributes.get("grid_x_count")? as usize;
+        let y_count = *self.attributes.get("grid_y_count")? as usize;
+        if i > x_count || j > y_count {
+            return None;
+        }
+        let key = j * (x_count + 1) + i;
+        self.vertex.contains_key(&key).then_some(key)
     }
 
     /// Create a low-resolution pipe mesh for backward compatibility.
     /// 8-sided cylinder with radius and length based on start/end points.
     pub fn create_pipe(start: Point, end: Point, thickness: f32) -> Self {
+        Self::create_pipe_with_sides(start, end, thickness, 8)
+    }
+
+    /// Create an open pipe (cylinder side faces only) with a configurable number of sides.
+    /// `sides` is clamped to at least 3.
+    pub fn create_pipe_with_sides(start: Point, end: Point, thickness: f32, sides: usize) -> Self {
         let mut m = Mesh::new();
-        let sides: usize = 8;
+        let sides = sides.max(3);
         let r = thickness * 0.5;
         
         // Calculate direction and length
@@ -1236,6 +3438,63 @@ impl Mesh {
         m
     }
 
+    /// Create an arrow mesh: an open pipe shaft followed by a cone head ending at `end`.
+    ///
+    /// The head is `3 * thickness` long (at most half the arrow) and twice as wide as
+    /// the shaft; its base is closed by a cap face. With `sides` segments the mesh has
+    /// `3 * sides + 1` vertices. Returns an empty mesh for zero-length arrows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let arrow = Mesh::create_arrow(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 2.0), 0.1, 12);
+    /// assert_eq!(arrow.number_of_vertices(), 37);
+    /// ```
+    pub fn create_arrow(start: Point, end: Point, thickness: f32, sides: usize) -> Self {
+        let sides = sides.max(3);
+        let dir = Vector::new(end.x - start.x, end.y - start.y, end.z - start.z);
+        let length = dir.length();
+        if length < 1e-9 {
+            return Mesh::new();
+        }
+        let axis = dir / length;
+        let head_length = (3.0 * thickness).min(0.5 * length);
+        let head_base = Point::new(
+            end.x - axis.x * head_length,
+            end.y - axis.y * head_length,
+            end.z - axis.z * head_length,
+        );
+
+        let mut m = Self::create_pipe_with_sides(start, head_base, thickness, sides);
+
+        // Cone head: base ring with twice the shaft radius plus the apex
+        let helper = if axis.z.abs() < 0.9 { Vector::new(0.0, 0.0, 1.0) } else { Vector::new(1.0, 0.0, 0.0) };
+        let mut u = axis.cross(&helper);
+        u.unitize();
+        let v = axis.cross(&u);
+        let r = thickness;
+        let mut base: Vec<usize> = Vec::with_capacity(sides);
+        for i in 0..sides {
+            let theta = 2.0 * PI * (i as f32) / (sides as f32);
+            let (sin_t, cos_t) = theta.sin_cos();
+            base.push(m.add_vertex(Point::new(
+                head_base.x + r * (cos_t * u.x + sin_t * v.x),
+                head_base.y + r * (cos_t * u.y + sin_t * v.y),
+                head_base.z + r * (cos_t * u.z + sin_t * v.z),
+            ), None));
+        }
+        let apex = m.add_vertex(end, None);
+        for i in 0..base.len() {
+            let j = (i + 1) % base.len();
+            m.add_face(vec![base[i], base[j], apex], None);
+        }
+        let mut cap = base.clone();
+        cap.reverse();
+        m.add_face(cap, None);
+        m
+    }
+
     /// Resolve vertex normal with fallback hierarchy:
     /// 1. Stored per-vertex nx,ny,nz attributes
     /// 2. Computed area-weighted vertex normal
@@ -1343,13 +3602,262 @@ impl Mesh {
         self.triangulation.iter().map(|(&k, v)| (k, v))
     }
 
+    /// Axis-aligned bounding box of all vertices as `(min, max)` corners.
+    ///
+    /// # Returns
+    /// None if the mesh has no vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Point::new(1.0, -2.0, 3.0), None);
+    /// mesh.add_vertex(Point::new(-1.0, 2.0, 0.0), None);
+    /// let (min, max) = mesh.bounding_box().unwrap();
+    /// assert_eq!(min, Point::new(-1.0, -2.0, 0.0));
+    /// assert_eq!(max, Point::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let mut vertices = self.vertex.values();
+        let first = vertices.next()?.position();
+        let (mut min, mut max) = (first, first);
+        for v in vertices {
+            min = Point::new(min.x.min(v.x), min.y.min(v.y), min.z.min(v.z));
+            max = Point::new(max.x.max(v.x), max.y.max(v.y), max.z.max(v.z));
+        }
+        Some((min, max))
+    }
+
+    /// Average of all vertex positions; the origin for a mesh without vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// mesh.add_vertex(Point::new(2.0, 4.0, 6.0), None);
+    /// assert_eq!(mesh.centroid(), Point::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn centroid(&self) -> Point {
+        let positions: Vec<Point> = self.vertex.values().map(|v| v.position()).collect();
+        Point::centroid(&positions).unwrap_or_else(Point::origin)
+    }
+
+    /// Area-weighted centroid of the faces: the average of `face_centroid` weighted
+    /// by `face_area`. Unlike `centroid` it doesn't depend on how densely the surface
+    /// is sampled by vertices.
+    ///
+    /// # Returns
+    /// None if the total face area is zero.
+    pub fn center_of_mass(&self) -> Option<Point> {
+        let (mut x, mut y, mut z, mut total) = (0.0, 0.0, 0.0, 0.0);
+        for fk in self.face.keys() {
+            if let (Some(c), Some(area)) = (self.face_centroid(*fk), self.face_area(*fk)) {
+                x += c.x * area;
+                y += c.y * area;
+                z += c.z * area;
+                total += area;
+            }
+        }
+        if total > 0.0 {
+            Some(Point::new(x / total, y / total, z / total))
+        } else {
+            None
+        }
+    }
+
+    /// Signed enclosed volume by the divergence theorem.
+    ///
+    /// Each face is triangulated like `triangulate_face_vertices` and every triangle
+    /// contributes the signed volume of the tetrahedron it forms with the origin.
+    /// The result is only meaningful for closed, consistently oriented meshes: it is
+    /// positive for outward-facing normals, negative for inward-facing ones, and
+    /// for open meshes it depends on where the mesh sits relative to the origin.
+    /// Unlike `mass_properties` it never rejects a mesh and keeps the sign, so it
+    /// doubles as an orientation check.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let o = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let x = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let y = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// let z = mesh.add_vertex(Point::new(0.0, 0.0, 1.0), None);
+    /// mesh.add_face(vec![o, y, x], None);
+    /// mesh.add_face(vec![o, x, z], None);
+    /// mesh.add_face(vec![o, z, y], None);
+    /// mesh.add_face(vec![x, y, z], None);
+    /// assert!((mesh.volume() - 1.0 / 6.0).abs() < 1e-6);
+    /// ```
+    pub fn volume(&self) -> f32 {
+        let mut volume = 0.0;
+        for fverts in self.face.values() {
+            for [pa, pb, pc] in self.face_triangle_points(fverts) {
+                let va = Vector::new(pa.x, pa.y, pa.z);
+                let vb = Vector::new(pb.x, pb.y, pb.z);
+                let vc = Vector::new(pc.x, pc.y, pc.z);
+                volume += va.dot(&vb.cross(&vc));
+            }
+        }
+        volume / 6.0
+    }
+
+    /// Compute volume, volume centroid and inertia tensor of a closed mesh.
+    ///
+    /// Faces are triangulated and each triangle forms a signed tetrahedron with the
+    /// origin; the volume integrals follow Eberly's polyhedral mass properties.
+    /// Inward-facing meshes are handled by flipping the sign of the integrals.
+    ///
+    /// # Returns
+    /// `None` if the mesh is not watertight (has boundary halfedges) or encloses no volume.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert!(mesh.mass_properties().is_none());
+    /// ```
+    pub fn mass_properties(&self) -> Option<MassProperties> {
+        if self.face.is_empty() {
+            return None;
+        }
+        let watertight = self
+            .halfedge
+            .values()
+            .all(|neigh| neigh.values().all(|f| f.is_some()));
+        if !watertight {
+            return None;
+        }
+
+        // Integrals of 1, x, y, z, x^2, y^2, z^2, xy, yz, zx over the volume
+        let mut intg = [0.0f32; 10];
+        for fverts in self.face.values() {
+            for [p0, p1, p2] in self.face_triangle_points(fverts) {
+                let (a1, b1, c1) = (p1.x - p0.x, p1.y - p0.y, p1.z - p0.z);
+                let (a2, b2, c2) = (p2.x - p0.x, p2.y - p0.y, p2.z - p0.z);
+                let d0 = b1 * c2 - b2 * c1;
+                let d1 = a2 * c1 - a1 * c2;
+                let d2 = a1 * b2 - a2 * b1;
+
+                let (f1x, f2x, f3x, g0x, g1x, g2x) = mass_subexpressions(p0.x, p1.x, p2.x);
+                let (_, f2y, f3y, g0y, g1y, g2y) = mass_subexpressions(p0.y, p1.y, p2.y);
+                let (_, f2z, f3z, g0z, g1z, g2z) = mass_subexpressions(p0.z, p1.z, p2.z);
+
+                intg[0] += d0 * f1x;
+                intg[1] += d0 * f2x;
+                intg[2] += d1 * f2y;
+                intg[3] += d2 * f2z;
+                intg[4] += d0 * f3x;
+                intg[5] += d1 * f3y;
+                intg[6] += d2 * f3z;
+                intg[7] += d0 * (p0.y * g0x + p1.y * g1x + p2.y * g2x);
+                intg[8] += d1 * (p0.z * g0y + p1.z * g1y + p2.z * g2y);
+                intg[9] += d2 * (p0.x * g0z + p1.x * g1z + p2.x * g2z);
+            }
+        }
+
+        let mult = [
+            1.0 / 6.0, 1.0 / 24.0, 1.0 / 24.0, 1.0 / 24.0,
+            1.0 / 60.0, 1.0 / 60.0, 1.0 / 60.0,
+            1.0 / 120.0, 1.0 / 120.0, 1.0 / 120.0,
+        ];
+        for (value, m) in intg.iter_mut().zip(mult.iter()) {
+            *value *= m;
+        }
+
+        // Faces oriented inward produce a negative volume
+        if intg[0] < 0.0 {
+            for value in intg.iter_mut() {
+                *value = -*value;
+            }
+        }
+
+        let volume = intg[0];
+        if volume <= 1e-12 {
+            return None;
+        }
+        let cx = intg[1] / volume;
+        let cy = intg[2] / volume;
+        let cz = intg[3] / volume;
+
+        let ixx = intg[5] + intg[6] - volume * (cy * cy + cz * cz);
+        let iyy = intg[4] + intg[6] - volume * (cz * cz + cx * cx);
+        let izz = intg[4] + intg[5] - volume * (cx * cx + cy * cy);
+        let ixy = -(intg[7] - volume * cx * cy);
+        let iyz = -(intg[8] - volume * cy * cz);
+        let izx = -(intg[9] - volume * cz * cx);
+
+        Some(MassProperties {
+            volume,
+            centroid: Point::new(cx, cy, cz),
+            inertia: [
+                [ixx, ixy, izx],
+                [ixy, iyy, iyz],
+                [izx, iyz, izz],
+            ],
+        })
+    }
+
+    /// Color vertices by a scalar field, interpolating between `low` and `high`.
+    ///
+    /// Values are normalized to [0, 1] over their min/max range; if all values are
+    /// equal every vertex with a value gets `low`. Vertices without a value get `low`.
+    ///
+    /// # Arguments
+    /// * `values` - Scalar value per vertex key
+    /// * `low` - Color assigned to the minimum value
+    /// * `high` - Color assigned to the maximum value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point, Color};
+    /// use std::collections::HashMap;
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let values = HashMap::from([(a, 0.0), (b, 10.0)]);
+    /// mesh.color_by_scalar(&values, &Color::blue(), &Color::red());
+    /// assert_eq!(mesh.vertex_color(b), Some(Color::red()));
+    /// ```
+    pub fn color_by_scalar(&mut self, values: &HashMap<usize, f32>, low: &Color, high: &Color) {
+        let mut min = f32::INFINITY;
+        let mut max = f32::NEG_INFINITY;
+        for (vk, &value) in values {
+            if self.vertex.contains_key(vk) && value.is_finite() {
+                min = min.min(value);
+                max = max.max(value);
+            }
+        }
+        let range = max - min;
+
+        let keys: Vec<usize> = self.vertex.keys().copied().collect();
+        for vk in keys {
+            let t = match values.get(&vk) {
+                Some(&value) if value.is_finite() && range > 0.0 => (value - min) / range,
+                _ => 0.0,
+            };
+            let color = low.lerp(high, t);
+            self.set_vertex_color(vk, &color);
+        }
+    }
+
 }
 
     impl Mesh {
         /// Load a mesh from a JSON file using the legacy JSON format:
         /// { "cube": { "polygons": [ { "vertices": [{"x":...,"y":...,"z":...}, ...] }, ... ] } }
         /// Additionally supports a root-level { "polygons": [...] } with the same structure.
-        pub fn from_json_file(file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        pub fn from_json_file(file_path: &str) -> Result<Self, crate::Error> {
             let content = std::fs::read_to_string(file_path)?;
             let json_data: serde_json::Value = serde_json::from_str(&content)?;
 
@@ -1397,8 +3905,84 @@ impl Mesh {
                 }
             }
 
-            Err("Invalid JSON format or missing polygons".into())
+            Err(crate::Error::Parse("invalid JSON format or missing polygons".to_string()))
+    }
+
+    /// Copy the vertices, faces and attributes of `other` into this mesh under fresh keys,
+    /// so keys never collide. Coincident vertices are not welded, see `weld_grid`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// let copy = mesh.clone();
+    /// mesh.merge(&copy);
+    /// assert_eq!(mesh.number_of_vertices(), 6);
+    /// assert_eq!(mesh.number_of_faces(), 2);
+    /// ```
+    pub fn merge(&mut self, other: &Mesh) {
+        let mut vkeys: Vec<usize> = other.vertex.keys().copied().collect();
+        vkeys.sort_unstable();
+        let mut vmap: HashMap<usize, usize> = HashMap::with_capacity(vkeys.len());
+        for vk in vkeys {
+            let vd = &other.vertex[&vk];
+            let key = self.add_vertex(vd.position(), None);
+            if let Some(new_vd) = self.vertex.get_mut(&key) {
+                new_vd.attributes = vd.attributes.clone();
+            }
+            vmap.insert(vk, key);
+        }
+
+        let mut fkeys: Vec<usize> = other.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        for fk in fkeys {
+            let fverts: Vec<usize> = other.face[&fk].iter().map(|v| vmap[v]).collect();
+            if let Some(key) = self.add_face(fverts, None) {
+                if let Some(attrs) = other.facedata.get(&fk) {
+                    self.facedata.insert(key, attrs.clone());
+                }
+            }
+        }
+
+        for ((u, v), attrs) in &other.edgedata {
+            if let (Some(&u), Some(&v)) = (vmap.get(u), vmap.get(v)) {
+                self.edgedata.insert((u, v), attrs.clone());
+            }
+        }
+    }
+}
+
+/// Join many meshes into one without welding coincident vertices.
+///
+/// Every input is copied under fresh vertex and face keys, so keys never collide.
+/// Vertex, face and edge attributes are kept.
+///
+/// # Example
+///
+/// ```
+/// use openmodel::geometry::{join_meshes, Mesh, Point};
+/// let mut tri = Mesh::new();
+/// let a = tri.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+/// let b = tri.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+/// let c = tri.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+/// tri.add_face(vec![a, b, c], None);
+/// let joined = join_meshes(&[tri.clone(), tri]);
+/// assert_eq!(joined.number_of_vertices(), 6);
+/// assert_eq!(joined.number_of_faces(), 2);
+/// ```
+pub fn join_meshes(meshes: &[Mesh]) -> Mesh {
+    let vertices = meshes.iter().map(|m| m.vertex.len()).sum();
+    let faces = meshes.iter().map(|m| m.face.len()).sum();
+    let mut joined = Mesh::with_capacity(vertices, faces);
+    for mesh in meshes {
+        joined.merge(mesh);
     }
+    joined
 }
 
 /// Project a 3D polygon to 2D for triangulation.
@@ -1451,9 +4035,9 @@ fn project_polygon_to_2d(polygon: &[Point]) -> Vec<[f32; 2]> {
     points_2d
 }
 
-/// Triangulate a polygon using the ear clipping algorithm
-#[allow(dead_code)]
-fn earclip_triangulate(points: &[[f32; 2]]) -> Result<Vec<[usize; 3]>, &'static str> {
+/// Triangulate a simple 2D polygon using the ear clipping algorithm.
+/// Triangles index into `points` and keep the winding of the input polygon.
+pub(crate) fn earclip_triangulate(points: &[[f32; 2]]) -> Result<Vec<[usize; 3]>, &'static str> {
     if points.len() < 3 {
         return Err("Polygon must have at least 3 vertices");
     }
@@ -1600,6 +4184,21 @@ fn newell_normal(points: &[Point]) -> (f32, f32, f32) {
     if len > 0.0 { (nx / len, ny / len, nz / len) } else { (0.0, 0.0, 1.0) }
 }
 
+/// Per-axis subexpressions of the triangle integrals used by `Mesh::mass_properties`.
+/// Returns (f1, f2, f3, g0, g1, g2) for the coordinates w0, w1, w2.
+fn mass_subexpressions(w0: f32, w1: f32, w2: f32) -> (f32, f32, f32, f32, f32, f32) {
+    let temp0 = w0 + w1;
+    let f1 = temp0 + w2;
+    let temp1 = w0 * w0;
+    let temp2 = temp1 + w1 * temp0;
+    let f2 = temp2 + w2 * f1;
+    let f3 = w0 * temp1 + w1 * temp2 + w2 * f2;
+    let g0 = f2 + w0 * (f1 + w0);
+    let g1 = f2 + w1 * (f1 + w1);
+    let g2 = f2 + w2 * (f1 + w2);
+    (f1, f2, f3, g0, g1, g2)
+}
+
 /// Compute standard signed area of a 2D polygon (CCW positive).
 fn signed_area_2d(points: &[[f32; 2]]) -> f32 {
     let n = points.len();
@@ -2084,6 +4683,7 @@ impl Mesh {
             "default_vertex_attributes": self.default_vertex_attributes,
             "default_face_attributes": self.default_face_attributes,
             "default_edge_attributes": self.default_edge_attributes,
+            "attributes": self.attributes,
             "max_vertex": self.max_vertex,
             "max_face": self.max_face
         })
@@ -2108,6 +4708,341 @@ impl Mesh {
     pub fn to_json_data(&self, minimal: bool) -> serde_json::Value {
         self.data.to_json_data(self.dtype(), self.geometric_data(), minimal)
     }
+
+    /// Export the mesh in the COMPAS `compas.datastructures/Mesh` data layout.
+    ///
+    /// Keys are strings as in COMPAS. Vertex attributes are stored next to `x`, `y`, `z`,
+    /// edge attributes under `"u-v"` keys. No halfedges are written: COMPAS rebuilds
+    /// them from the faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// let json = mesh.to_compas_json();
+    /// assert_eq!(json["dtype"], "compas.datastructures/Mesh");
+    /// assert_eq!(json["data"]["vertex"][a.to_string()]["x"], 0.0);
+    /// ```
+    pub fn to_compas_json(&self) -> serde_json::Value {
+        let vertices: serde_json::Map<String, serde_json::Value> = self.vertex.iter()
+            .map(|(k, v)| {
+                let mut attrs: serde_json::Map<String, serde_json::Value> = v.attributes.iter()
+                    .map(|(name, value)| (name.clone(), serde_json::json!(value)))
+                    .collect();
+                attrs.insert("x".to_string(), serde_json::json!(v.x));
+                attrs.insert("y".to_string(), serde_json::json!(v.y));
+                attrs.insert("z".to_string(), serde_json::json!(v.z));
+                (k.to_string(), serde_json::Value::Object(attrs))
+            })
+            .collect();
+        let faces: HashMap<String, &Vec<usize>> = self.face.iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let facedata: HashMap<String, &HashMap<String, f32>> = self.facedata.iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let edgedata: HashMap<String, &HashMap<String, f32>> = self.edgedata.iter()
+            .map(|((u, v), attrs)| (format!("{}-{}", u, v), attrs))
+            .collect();
+        let mut attributes = serde_json::json!(self.attributes);
+        attributes["name"] = serde_json::json!(self.data.name());
+
+        serde_json::json!({
+            "dtype": "compas.datastructures/Mesh",
+            "data": {
+                "attributes": attributes,
+                "default_vertex_attributes": self.default_vertex_attributes,
+                "default_edge_attributes": self.default_edge_attributes,
+                "default_face_attributes": self.default_face_attributes,
+                "vertex": vertices,
+                "face": faces,
+                "facedata": facedata,
+                "edgedata": edgedata,
+                "max_vertex": self.vertex.keys().max().copied(),
+                "max_face": self.face.keys().max().copied()
+            }
+        })
+    }
+
+    /// Export the mesh as Wavefront OBJ text.
+    ///
+    /// Vertices are written as `v x y z` lines in key order and faces as `f i j k ...`
+    /// lines with 1-based indices, in face key order (see `to_vertices_and_faces`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// assert!(mesh.to_obj().ends_with("v 0 1 0\nf 1 2 3\n"));
+    /// ```
+    pub fn to_obj(&self) -> String {
+        use std::fmt::Write;
+
+        let (vertices, faces) = self.to_vertices_and_faces();
+        let mut obj = String::new();
+        let _ = writeln!(obj, "# openmodel mesh: {} vertices, {} faces", vertices.len(), faces.len());
+        for p in &vertices {
+            let _ = writeln!(obj, "v {} {} {}", p.x, p.y, p.z);
+        }
+        for face in &faces {
+            let indices: Vec<String> = face.iter().map(|i| (i + 1).to_string()).collect();
+            let _ = writeln!(obj, "f {}", indices.join(" "));
+        }
+        obj
+    }
+
+    /// Write the mesh to a Wavefront OBJ file, see `to_obj`.
+    pub fn write_obj(&self, path: &str) -> std::io::Result<()> {
+        std::fs::write(path, self.to_obj())
+    }
+
+    /// Triangles for STL export in face key order, each with the normal of its face
+    /// (zero for degenerate faces).
+    fn stl_triangles(&self) -> Vec<(Vector, [Point; 3])> {
+        let mut fkeys: Vec<usize> = self.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        let mut out = Vec::with_capacity(fkeys.len());
+        for fk in fkeys {
+            let normal = self.face_normal(fk).unwrap_or_else(|| Vector::new(0.0, 0.0, 0.0));
+            for triangle in self.face_triangle_points(&self.face[&fk]) {
+                out.push((normal, triangle));
+            }
+        }
+        out
+    }
+
+    /// Export the mesh as ASCII STL text.
+    ///
+    /// Faces are triangulated with `triangulate_face_vertices` (a fan for convex faces)
+    /// and every triangle carries the normal of its face from `face_normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Mesh, Point};
+    /// let mut mesh = Mesh::new();
+    /// let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    /// let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    /// let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    /// mesh.add_face(vec![a, b, c], None);
+    /// let stl = mesh.to_stl_ascii();
+    /// assert!(stl.starts_with("solid"));
+    /// assert_eq!(stl.matches("facet normal 0 0 1").count(), 1);
+    /// ```
+    pub fn to_stl_ascii(&self) -> String {
+        use std::fmt::Write;
+
+        let name = if self.data.name().is_empty() { "mesh" } else { self.data.name() };
+        let mut stl = String::new();
+        let _ = writeln!(stl, "solid {}", name);
+        for (n, tri) in self.stl_triangles() {
+            let _ = writeln!(stl, "  facet normal {} {} {}", n.x, n.y, n.z);
+            let _ = writeln!(stl, "    outer loop");
+            for p in &tri {
+                let _ = writeln!(stl, "      vertex {} {} {}", p.x, p.y, p.z);
+            }
+            let _ = writeln!(stl, "    endloop");
+            let _ = writeln!(stl, "  endfacet");
+        }
+        let _ = writeln!(stl, "endsolid {}", name);
+        stl
+    }
+
+    /// Export the mesh as binary STL: an 80-byte header, the little-endian `u32`
+    /// triangle count, then 50 bytes per triangle (normal, three vertices as `f32`
+    /// and a zero attribute byte count). Triangulation as in `to_stl_ascii`.
+    pub fn to_stl_binary(&self) -> Vec<u8> {
+        let triangles = self.stl_triangles();
+        let mut bytes = Vec::with_capacity(84 + 50 * triangles.len());
+
+        let mut header = [0u8; 80];
+        let title = b"openmodel binary STL";
+        header[..title.len()].copy_from_slice(title);
+        bytes.extend_from_slice(&header);
+        bytes.extend_from_slice(&(triangles.len() as u32).to_le_bytes());
+
+        for (n, tri) in &triangles {
+            for value in [n.x, n.y, n.z] {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            for p in tri {
+                for value in [p.x, p.y, p.z] {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            bytes.extend_from_slice(&0u16.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Create a mesh from Wavefront OBJ text.
+    ///
+    /// Only `v` and `f` statements are used; comments and other statements (`vt`, `vn`,
+    /// `g`, ...) are ignored. Face entries like `f 1/2/3` use the index before the first
+    /// slash, and negative indices count back from the last vertex read so far.
+    ///
+    /// # Errors
+    /// `Error::Parse` with the line number for a malformed `v` or `f` line, an index out of
+    /// range, or a face the halfedge structure can't accept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Mesh;
+    /// let mesh = Mesh::from_obj("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1/1 2/2 -1\n").unwrap();
+    /// assert_eq!(mesh.number_of_vertices(), 3);
+    /// assert_eq!(mesh.number_of_faces(), 1);
+    /// ```
+    pub fn from_obj(text: &str) -> Result<Mesh, crate::Error> {
+        let mut mesh = Mesh::new();
+        let mut keys: Vec<usize> = Vec::new();
+
+        for (n, line) in text.lines().enumerate() {
+            let line_no = n + 1;
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let coords: Vec<f32> = tokens
+                        .take(3)
+                        .map(|t| t.parse::<f32>())
+                        .collect::<Result<_, _>>()
+                        .map_err(|e| crate::Error::Parse(format!("line {}: invalid vertex coordinate: {}", line_no, e)))?;
+                    if coords.len() < 3 {
+                        return Err(crate::Error::Parse(format!("line {}: vertex needs 3 coordinates", line_no)));
+                    }
+                    keys.push(mesh.add_vertex(Point::new(coords[0], coords[1], coords[2]), None));
+                }
+                Some("f") => {
+                    let mut face = Vec::new();
+                    for token in tokens {
+                        let index_str = token.split('/').next().unwrap_or("");
+                        let index: i64 = index_str
+                            .parse()
+                            .map_err(|_| crate::Error::Parse(format!("line {}: invalid face index '{}'", line_no, token)))?;
+                        let resolved = if index > 0 { index - 1 } else { keys.len() as i64 + index };
+                        if index == 0 || resolved < 0 || resolved >= keys.len() as i64 {
+                            return Err(crate::Error::Parse(format!(
+                                "line {}: face index {} out of range for {} vertices",
+                                line_no, index, keys.len()
+                            )));
+                        }
+                        face.push(keys[resolved as usize]);
+                    }
+                    if face.len() < 3 {
+                        return Err(crate::Error::Parse(format!("line {}: face needs at least 3 vertices", line_no)));
+                    }
+                    if mesh.add_face(face, None).is_none() {
+                        return Err(crate::Error::Parse(format!("line {}: invalid face", line_no)));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(mesh)
+    }
+
+    /// Create a mesh from COMPAS mesh JSON, either the `{dtype, data}` wrapper or the
+    /// bare data object. Vertex and face keys are kept; halfedges are rebuilt from the faces.
+    ///
+    /// # Returns
+    /// None if `vertex` or `face` is missing, a key or coordinate is invalid, or a face
+    /// cannot be added (e.g. it references a missing vertex).
+    pub fn from_compas_json(value: &serde_json::Value) -> Option<Mesh> {
+        let data = value.get("data").unwrap_or(value);
+        let mut mesh = Mesh::new();
+        if let Some(name) = data.get("attributes").and_then(|a| a.get("name")).and_then(|n| n.as_str()) {
+            mesh.data.set_name(name);
+        }
+
+        let vertices = data.get("vertex")?.as_object()?;
+        let mut vkeys: Vec<(usize, &serde_json::Map<String, serde_json::Value>)> = Vec::with_capacity(vertices.len());
+        for (key, attrs) in vertices {
+            vkeys.push((key.parse().ok()?, attrs.as_object()?));
+        }
+        vkeys.sort_unstable_by_key(|(key, _)| *key);
+        for (key, attrs) in vkeys {
+            let coord = |name: &str| attrs.get(name).and_then(|v| v.as_f64()).map(|v| v as f32);
+            mesh.add_vertex(Point::new(coord("x")?, coord("y")?, coord("z")?), Some(key));
+            let vd = mesh.vertex.get_mut(&key)?;
+            for (name, attr) in attrs {
+                if let (false, Some(attr)) = (matches!(name.as_str(), "x" | "y" | "z"), attr.as_f64()) {
+                    vd.set_attribute(name, attr as f32);
+                }
+            }
+        }
+
+        let faces = data.get("face")?.as_object()?;
+        let mut fkeys: Vec<(usize, Vec<usize>)> = Vec::with_capacity(faces.len());
+        for (key, fverts) in faces {
+            fkeys.push((key.parse().ok()?, serde_json::from_value(fverts.clone()).ok()?));
+        }
+        fkeys.sort_unstable_by_key(|(key, _)| *key);
+        for (key, fverts) in fkeys {
+            mesh.add_face(fverts, Some(key))?;
+        }
+
+        let attribute_map = |v: &serde_json::Value| -> HashMap<String, f32> {
+            v.as_object()
+                .map(|o| o.iter().filter_map(|(k, x)| Some((k.clone(), x.as_f64()? as f32))).collect())
+                .unwrap_or_default()
+        };
+        if let Some(facedata) = data.get("facedata").and_then(|f| f.as_object()) {
+            for (key, attrs) in facedata {
+                if let Ok(key) = key.parse::<usize>() {
+                    mesh.facedata.insert(key, attribute_map(attrs));
+                }
+            }
+        }
+        if let Some(edgedata) = data.get("edgedata").and_then(|e| e.as_object()) {
+            for (key, attrs) in edgedata {
+                let mut parts = key.split(['-', ',']).map(|p| p.trim().trim_matches(|c| c == '(' || c == ')').parse::<usize>());
+                if let (Some(Ok(u)), Some(Ok(v)), None) = (parts.next(), parts.next(), parts.next()) {
+                    mesh.edgedata.insert((u, v), attribute_map(attrs));
+                }
+            }
+        }
+        for (field, target) in [
+            ("default_vertex_attributes", &mut mesh.default_vertex_attributes),
+            ("default_edge_attributes", &mut mesh.default_edge_attributes),
+            ("default_face_attributes", &mut mesh.default_face_attributes),
+        ] {
+            if let Some(defaults) = data.get(field) {
+                target.extend(attribute_map(defaults));
+            }
+        }
+        if let Some(attributes) = data.get("attributes") {
+            mesh.attributes = attribute_map(attributes);
+        }
+        Some(mesh)
+    }
+}
+
+// Implement Transformable for Mesh
+impl Transformable for Mesh {
+    /// Transforms vertex positions and any stored vertex normals.
+    fn transform(&mut self, xform: &Xform) {
+        let normal_xform = xform.normal_matrix();
+        for vd in self.vertex.values_mut() {
+            vd.set_position(xform.transform_point(&vd.position()));
+            if let Some([nx, ny, nz]) = vd.normal() {
+                let mut n = normal_xform.transform_vector(&Vector::new(nx, ny, nz));
+                if n.unitize() {
+                    vd.set_normal(n.x, n.y, n.z);
+                }
+            }
+        }
+    }
 }
 
 // JSON serialization support
@@ -1,20 +1,24 @@
 pub mod line;
 pub mod arrow;
 pub mod plane;
+pub mod frame;
 pub mod pointcloud;
 pub mod linecloud;
 pub mod pline;
 pub mod mesh;
 pub mod pipe;
+pub mod ray;
 
-// Re-export primitive types for backward compatibility
-pub use crate::primitives::{Vector, Color, Xform, Point};
+// Re-export primitive types for backward compatibility (canonical definitions live in `primitives`)
+pub use crate::primitives::{Vector, Color, Xform, Point, Transformable, Coords};
 
 pub use line::Line;
 pub use arrow::Arrow;
 pub use plane::Plane;
+pub use frame::Frame;
 pub use pointcloud::PointCloud;
 pub use linecloud::LineCloud;
 pub use pline::Pline;
-pub use mesh::Mesh;
+pub use mesh::{join_meshes, Mesh};
+pub use ray::Ray;
 pub use pipe::{PipeFromSegments, SphereFromSegments, dedupe_sphere_transforms};
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::geometry::Point;
 use crate::geometry::Vector;
 use crate::common::{JsonSerializable, FromJsonData};
+use crate::primitives::{Coords, Transformable, Xform};
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 use crate::common::Data;
@@ -300,9 +301,122 @@ impl Plane{
 
     }
 
-    
+    /// Rotates the x and y axes in place about the plane normal, keeping the origin
+    /// and the plane equation.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The rotation angle in radians, counter-clockwise about `zaxis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::Plane;
+    /// let mut plane = Plane::default();
+    /// plane.rotate_about_normal(std::f32::consts::FRAC_PI_2);
+    /// assert!((plane.xaxis.y - 1.0).abs() < 1e-6);
+    /// assert!((plane.yaxis.x + 1.0).abs() < 1e-6);
+    /// ```
+    pub fn rotate_about_normal(&mut self, angle: f32) {
+        let (sin_a, cos_a) = angle.sin_cos();
+        let x = self.xaxis;
+        let y = self.yaxis;
+        self.xaxis = Vector::new(
+            cos_a * x.x + sin_a * y.x,
+            cos_a * x.y + sin_a * y.y,
+            cos_a * x.z + sin_a * y.z,
+        );
+        self.yaxis = Vector::new(
+            cos_a * y.x - sin_a * x.x,
+            cos_a * y.y - sin_a * x.y,
+            cos_a * y.z - sin_a * x.z,
+        );
+    }
+
+    /// Returns a copy of the plane rotated about an axis through a point.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The rotation axis direction (does not need to be unit length).
+    /// * `angle` - The rotation angle in radians.
+    /// * `about` - A point on the rotation axis.
+    ///
+    /// # Returns
+    ///
+    /// The rotated plane, or an unchanged copy if `axis` has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Plane, Point, Vector};
+    /// let plane = Plane::default();
+    /// let rotated = plane.rotated(&Vector::new(1.0, 0.0, 0.0), std::f32::consts::PI, &Point::new(0.0, 0.0, 1.0));
+    /// assert!((rotated.origin.z - 2.0).abs() < 1e-6);
+    /// assert!((rotated.zaxis.z + 1.0).abs() < 1e-6);
+    /// ```
+    pub fn rotated(&self, axis: &Vector, angle: f32, about: &Point) -> Plane {
+        let mut axis = *axis;
+        if !axis.unitize() {
+            return self.clone();
+        }
+        let xform = Xform::translation(about.x, about.y, about.z)
+            * Xform::rotation(&axis, angle)
+            * Xform::translation(-about.x, -about.y, -about.z);
+        self.transformed(&xform)
+    }
+
+    /// Returns the reflection across this plane, built from its `a, b, c, d` coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Plane, Point, Vector};
+    /// let plane = Plane::from_point_normal(&Point::new(2.0, 0.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    /// let p = plane.mirror_xform().transform_point(&Point::new(3.0, 1.0, 1.0));
+    /// assert_eq!((p.x, p.y, p.z), (1.0, 1.0, 1.0));
+    /// ```
+    pub fn mirror_xform(&self) -> Xform {
+        let normal = Vector::new(self.a, self.b, self.c);
+        let length_sq = normal.dot(&normal);
+        if length_sq == 0.0 {
+            return Xform::identity();
+        }
+        let s = -self.d / length_sq;
+        Xform::mirror(&Point::new(self.a * s, self.b * s, self.c * s), &normal)
+    }
 
+    /// Express a point in the plane's local (u, v, w) coordinates, measured from the
+    /// origin along the x, y and z axes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Plane, Point, Vector};
+    /// let plane = Plane::new(Point::new(1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+    /// assert_eq!(plane.to_uv(&Point::new(3.0, 2.0, 5.0)), (2.0, 2.0, 5.0));
+    /// ```
+    pub fn to_uv(&self, point: &Point) -> (f32, f32, f32) {
+        let d = Vector::new(point.x - self.origin.x, point.y - self.origin.y, point.z - self.origin.z);
+        (d.dot(&self.xaxis), d.dot(&self.yaxis), d.dot(&self.zaxis))
+    }
 
+    /// Point on the plane at parameters (u, v): `origin + u * xaxis + v * yaxis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Plane, Point};
+    /// let plane = Plane::default();
+    /// let p = plane.from_uv(2.0, 3.0);
+    /// assert_eq!((p.x, p.y, p.z), (2.0, 3.0, 0.0));
+    /// ```
+    pub fn from_uv(&self, u: f32, v: f32) -> Point {
+        Point::new(
+            self.origin.x + u * self.xaxis.x + v * self.yaxis.x,
+            self.origin.y + u * self.xaxis.y + v * self.yaxis.y,
+            self.origin.z + u * self.xaxis.z + v * self.yaxis.z,
+        )
+    }
 }
 
 impl Default for Plane {
@@ -470,6 +584,36 @@ impl SubAssign<&Vector> for Plane {
 }
 
 
+// Implement Transformable for Plane
+impl Transformable for Plane {
+    /// Transforms the origin and axes, re-orthonormalizes the frame and
+    /// recomputes the plane equation coefficients.
+    fn transform(&mut self, xform: &Xform) {
+        self.origin = xform.transform_point(&self.origin);
+        let mut xaxis = xform.transform_vector(&self.xaxis);
+        let yaxis = xform.transform_vector(&self.yaxis);
+        let mut zaxis = xaxis.cross(&yaxis);
+        if !xaxis.unitize() || !zaxis.unitize() {
+            return;
+        }
+        self.xaxis = xaxis;
+        self.yaxis = zaxis.cross(&xaxis);
+        self.zaxis = zaxis;
+        self.a = zaxis.x;
+        self.b = zaxis.y;
+        self.c = zaxis.z;
+        self.d = -(self.a * self.origin.x + self.b * self.origin.y + self.c * self.origin.z);
+    }
+}
+
+// Implement Coords for Plane
+impl Coords for Plane {
+    /// Returns the coordinates of the plane origin.
+    fn coords(&self) -> [f64; 3] {
+        self.origin.coords()
+    }
+}
+
 impl fmt::Display for Plane{
     /// Log color.
     /// # Example
@@ -2,6 +2,9 @@ use crate::geometry::Point;
 use crate::geometry::Vector;
 use crate::geometry::Plane;
 use crate::geometry::Mesh;
+use crate::geometry::Arrow;
+use crate::geometry::Color;
+use crate::primitives::{Transformable, Xform};
 use crate::common::{FromJsonData, HasJsonData};
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Sub, SubAssign};
@@ -16,6 +19,10 @@ pub struct Pline {
     /// The plane of the polyline.
     pub plane: Plane,
 
+    /// Optional per-vertex colors for gradient display (empty = use data color).
+    #[serde(default)]
+    pub colors: Vec<Color>,
+
     /// Associated data - guid and name.
     pub data: Data,
 }
@@ -45,10 +52,233 @@ impl Pline {
         Self {
             points,
             plane,
+            colors: Vec::new(),
             data: Data::default(),
         }
     }
+
+    /// Total length of the polyline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Pline};
+    /// let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0), Point::new(3.0, 4.0, 0.0)]);
+    /// assert_eq!(pline.length(), 7.0);
+    /// ```
+    pub fn length(&self) -> f32 {
+        self.points.windows(2).map(|w| w[0].distance(&w[1])).sum()
+    }
+
+    /// Color at an arc-length position along the polyline, interpolated between the
+    /// per-vertex `colors`.
+    ///
+    /// If `colors` is empty the data color is returned; if there are fewer colors
+    /// than points the last color is held for the remaining vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - Distance from the first point, clamped to [0, length]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Pline, Color};
+    /// let mut pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0)]);
+    /// pline.colors = vec![Color::red(), Color::blue()];
+    /// assert_eq!(pline.color_at_arc_length(2.0), Color::blue());
+    /// ```
+    pub fn color_at_arc_length(&self, s: f32) -> Color {
+        if self.colors.is_empty() {
+            let [r, g, b] = self.data.get_color();
+            return Color::rgb(r, g, b);
+        }
+        let color_at = |i: usize| self.colors[i.min(self.colors.len() - 1)];
+        if self.points.len() < 2 {
+            return color_at(0);
+        }
+
+        let mut remaining = s.max(0.0);
+        for i in 0..self.points.len() - 1 {
+            let seg = self.points[i].distance(&self.points[i + 1]);
+            if remaining <= seg {
+                let t = if seg > 0.0 { remaining / seg } else { 0.0 };
+                return color_at(i).lerp(&color_at(i + 1), t);
+            }
+            remaining -= seg;
+        }
+        color_at(self.points.len() - 1)
+    }
     
+    /// Splits the polyline at the point `s` units from the start, inserting a vertex there.
+    ///
+    /// Both parts keep the plane, name and style of this polyline (with new guids). When
+    /// `colors` are set they are split too, with the interpolated color at the cut.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - Distance from the first point, clamped to [0, length]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Pline};
+    /// let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0)]);
+    /// let (a, b) = pline.split_at_arc_length(4.0);
+    /// assert_eq!(a.length(), 4.0);
+    /// assert_eq!(b.length(), 6.0);
+    /// ```
+    pub fn split_at_arc_length(&self, s: f32) -> (Pline, Pline) {
+        if self.points.len() < 2 {
+            let first = self.split_part(self.points.clone(), self.colors.clone());
+            let second = self.split_part(self.points.clone(), self.colors.clone());
+            return (first, second);
+        }
+
+        // Segment containing s and the parameter on it
+        let last = self.points.len() - 2;
+        let mut remaining = s.max(0.0);
+        let mut segment = last;
+        let mut t = 1.0;
+        for i in 0..=last {
+            let seg = self.points[i].distance(&self.points[i + 1]);
+            if remaining <= seg {
+                segment = i;
+                t = if seg > 0.0 { remaining / seg } else { 0.0 };
+                break;
+            }
+            remaining -= seg;
+        }
+        let p0 = &self.points[segment];
+        let p1 = &self.points[segment + 1];
+        let cut = Point::new(p0.x + (p1.x - p0.x) * t, p0.y + (p1.y - p0.y) * t, p0.z + (p1.z - p0.z) * t);
+
+        let mut first: Vec<Point> = self.points[..=segment].to_vec();
+        let mut second: Vec<Point> = self.points[segment + 1..].to_vec();
+        if t > 0.0 {
+            first.push(cut);
+        }
+        if t < 1.0 {
+            second.insert(0, cut);
+        }
+
+        let (mut first_colors, mut second_colors) = (Vec::new(), Vec::new());
+        if !self.colors.is_empty() {
+            let color_at = |i: usize| self.colors[i.min(self.colors.len() - 1)];
+            let cut_color = color_at(segment).lerp(&color_at(segment + 1), t);
+            first_colors = (0..=segment).map(color_at).collect();
+            second_colors = (segment + 1..self.points.len()).map(color_at).collect();
+            if t > 0.0 {
+                first_colors.push(cut_color);
+            }
+            if t < 1.0 {
+                second_colors.insert(0, cut_color);
+            }
+        }
+
+        (self.split_part(first, first_colors), self.split_part(second, second_colors))
+    }
+
+    /// Splits the polyline at the point on it closest to `p`.
+    ///
+    /// See `split_at_arc_length` for how the parts are built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Pline};
+    /// let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0), Point::new(4.0, 4.0, 0.0)]);
+    /// let (a, b) = pline.split_at_closest_point(&Point::new(6.0, 1.0, 0.0));
+    /// assert_eq!(a.length(), 5.0);
+    /// assert_eq!(b.length(), 3.0);
+    /// ```
+    pub fn split_at_closest_point(&self, p: &Point) -> (Pline, Pline) {
+        let mut best_distance = f32::MAX;
+        let mut best_s = 0.0;
+        let mut start_s = 0.0;
+        for w in self.points.windows(2) {
+            let d = Vector::new(w[1].x - w[0].x, w[1].y - w[0].y, w[1].z - w[0].z);
+            let seg = d.length();
+            let t = if seg > 0.0 {
+                let to_p = Vector::new(p.x - w[0].x, p.y - w[0].y, p.z - w[0].z);
+                (to_p.dot(&d) / (seg * seg)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let q = Point::new(w[0].x + d.x * t, w[0].y + d.y * t, w[0].z + d.z * t);
+            let distance = q.distance(p);
+            if distance < best_distance {
+                best_distance = distance;
+                best_s = start_s + seg * t;
+            }
+            start_s += seg;
+        }
+        self.split_at_arc_length(best_s)
+    }
+
+    /// Builds one part of a split, keeping the plane and a copy of the data.
+    fn split_part(&self, points: Vec<Point>, colors: Vec<Color>) -> Pline {
+        Pline {
+            points,
+            plane: self.plane.clone(),
+            colors,
+            data: self.data.copy(false),
+        }
+    }
+
+    /// Fill a closed, planar polyline with triangles.
+    ///
+    /// The polygon is projected into the polyline's plane, ear clipped in (u, v) and
+    /// lifted back with `Plane::from_uv`. The triangles keep the polyline's winding.
+    ///
+    /// # Returns
+    ///
+    /// A triangle mesh, or None if the polyline is open, not planar, self-intersecting
+    /// or has fewer than three distinct corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Pline};
+    /// let square = Pline::new(vec![
+    ///     Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0),
+    ///     Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0),
+    ///     Point::new(0.0, 0.0, 0.0),
+    /// ]);
+    /// let mesh = square.to_filled_mesh().unwrap();
+    /// assert_eq!(mesh.number_of_faces(), 2);
+    /// ```
+    pub fn to_filled_mesh(&self) -> Option<Mesh> {
+        let n = self.points.len();
+        if n < 4 || self.points[0].distance(&self.points[n - 1]) > 1e-6 {
+            return None;
+        }
+        let corners = &self.points[..n - 1];
+        let tolerance = 1e-4 * self.length().max(1.0);
+
+        let mut uv: Vec<[f32; 2]> = Vec::with_capacity(corners.len());
+        for p in corners {
+            let (u, v, w) = self.plane.to_uv(p);
+            if w.abs() > tolerance {
+                return None;
+            }
+            uv.push([u, v]);
+        }
+        if polygon_self_intersects(&uv) {
+            return None;
+        }
+
+        let triangles = crate::geometry::mesh::earclip_triangulate(&uv).ok()?;
+        let mut mesh = Mesh::with_capacity(uv.len(), triangles.len());
+        let keys: Vec<usize> = uv.iter()
+            .map(|[u, v]| mesh.add_vertex(self.plane.from_uv(*u, *v), None))
+            .collect();
+        for [a, b, c] in triangles {
+            mesh.add_face(vec![keys[a], keys[b], keys[c]], None);
+        }
+        Some(mesh)
+    }
+
     /// Convert polyline segments to pipe meshes for visualization.
     /// Each segment between consecutive points becomes a cylindrical pipe mesh.
     /// 
@@ -112,6 +342,36 @@ impl Pline {
         
         meshes
     }
+
+    /// Convert polyline segments to arrows pointing from each point to the next.
+    /// The polyline's color (if set) and thickness are copied to every arrow.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Arrow` objects, one for each segment in the polyline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Pline};
+    /// let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+    /// let arrows = pline.to_arrows();
+    /// assert_eq!(arrows.len(), 1);
+    /// assert_eq!(arrows[0].x1, 1.0);
+    /// ```
+    pub fn to_arrows(&self) -> Vec<Arrow> {
+        self.points
+            .windows(2)
+            .map(|w| {
+                let mut arrow = Arrow::from_points(&w[0], &w[1]);
+                if self.data.has_color() {
+                    arrow.data.set_color(self.data.get_color());
+                }
+                arrow.data.set_thickness(self.data.get_thickness());
+                arrow
+            })
+            .collect()
+    }
 }
 
 
@@ -242,6 +502,16 @@ impl Sub<&Vector> for Pline {
     }
 }
 
+// Implement Transformable for Pline
+impl Transformable for Pline {
+    fn transform(&mut self, xform: &Xform) {
+        for p in &mut self.points {
+            p.transform(xform);
+        }
+        self.plane.transform(xform);
+    }
+}
+
 impl fmt::Display for Pline {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -258,7 +528,8 @@ impl HasJsonData for Pline {
     fn to_json_data(&self, minimal: bool) -> serde_json::Value {
         let geometric_data = serde_json::json!({
             "points": self.points,
-            "plane": self.plane
+            "plane": self.plane,
+            "colors": self.colors
         });
         self.data.to_json_data("openmodel.geometry/Pline", geometric_data, minimal)
     }
@@ -269,3 +540,37 @@ impl FromJsonData for Pline {
         serde_json::from_value(data.clone()).ok()
     }
 }
+
+/// True if two non-adjacent edges of the closed 2D polygon touch or cross.
+fn polygon_self_intersects(points: &[[f32; 2]]) -> bool {
+    let n = points.len();
+    let orient = |a: [f32; 2], b: [f32; 2], c: [f32; 2]| -> f32 {
+        (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+    };
+    let on_segment = |a: [f32; 2], b: [f32; 2], p: [f32; 2]| -> bool {
+        p[0] >= a[0].min(b[0]) && p[0] <= a[0].max(b[0]) && p[1] >= a[1].min(b[1]) && p[1] <= a[1].max(b[1])
+    };
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        for j in (i + 1)..n {
+            // Skip the edge itself and its two neighbours
+            if j == i + 1 || (i == 0 && j == n - 1) {
+                continue;
+            }
+            let (c, d) = (points[j], points[(j + 1) % n]);
+            let (d1, d2) = (orient(a, b, c), orient(a, b, d));
+            let (d3, d4) = (orient(c, d, a), orient(c, d, b));
+            if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+                return true;
+            }
+            if (d1 == 0.0 && on_segment(a, b, c))
+                || (d2 == 0.0 && on_segment(a, b, d))
+                || (d3 == 0.0 && on_segment(c, d, a))
+                || (d4 == 0.0 && on_segment(c, d, b))
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
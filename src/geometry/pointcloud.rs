@@ -1,4 +1,4 @@
-use crate::primitives::{Point, Vector, Color, Xform};
+use crate::primitives::{Point, Vector, Color, Xform, Transformable};
 use crate::common::{FromJsonData, HasJsonData, Data};
 use serde::{Deserialize, Serialize, Serializer};
 use std::ops::{Add, AddAssign, Sub, SubAssign};
@@ -54,6 +54,83 @@ impl PointCloud {
             data: Data::default(),
         }
     }
+
+    /// Approximate bounding sphere of the points using Ritter's algorithm.
+    ///
+    /// The sphere contains every point but can be up to a few percent larger than the
+    /// minimal one. Computed on `points` as stored, without applying `xform`.
+    ///
+    /// # Returns
+    ///
+    /// The center and radius, or None for an empty cloud.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, PointCloud};
+    /// let cloud = PointCloud::new(vec![Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)], vec![], vec![]);
+    /// let (center, radius) = cloud.bounding_sphere().unwrap();
+    /// assert_eq!(center, Point::new(0.0, 0.0, 0.0));
+    /// assert_eq!(radius, 1.0);
+    /// ```
+    pub fn bounding_sphere(&self) -> Option<(Point, f32)> {
+        let first = *self.points.first()?;
+        let farthest = |from: &Point| -> Point {
+            self.points.iter().copied()
+                .fold((first, -1.0_f32), |(best, best_d), p| {
+                    let d = from.distance(&p);
+                    if d > best_d { (p, d) } else { (best, best_d) }
+                })
+                .0
+        };
+        let a = farthest(&first);
+        let b = farthest(&a);
+        let mut center = a.midpoint(&b);
+        let mut radius = a.distance(&b) * 0.5;
+
+        // Grow the sphere just enough to include every point outside it
+        for p in &self.points {
+            let d = center.distance(p);
+            if d > radius {
+                let new_radius = (radius + d) * 0.5;
+                let shift = (new_radius - radius) / d;
+                center = Point::new(
+                    center.x + (p.x - center.x) * shift,
+                    center.y + (p.y - center.y) * shift,
+                    center.z + (p.z - center.z) * shift,
+                );
+                radius = new_radius;
+            }
+        }
+        Some((center, radius))
+    }
+
+    /// Number of points per unit volume of their axis-aligned bounding box.
+    ///
+    /// # Returns
+    ///
+    /// 0 for an empty cloud or when the points are flat along an axis (zero volume).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, PointCloud};
+    /// let cloud = PointCloud::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(2.0, 2.0, 2.0)], vec![], vec![]);
+    /// assert_eq!(cloud.density(), 0.25);
+    /// ```
+    pub fn density(&self) -> f32 {
+        let Some(first) = self.points.first() else {
+            return 0.0;
+        };
+        let (min, max) = self.points.iter().fold((*first, *first), |(lo, hi), p| {
+            (
+                Point::new(lo.x.min(p.x), lo.y.min(p.y), lo.z.min(p.z)),
+                Point::new(hi.x.max(p.x), hi.y.max(p.y), hi.z.max(p.z)),
+            )
+        });
+        let volume = (max.x - min.x) * (max.y - min.y) * (max.z - min.z);
+        if volume > 0.0 { self.points.len() as f32 / volume } else { 0.0 }
+    }
 }
 
 
@@ -192,6 +269,21 @@ impl Serialize for PointCloud {
     }
 }
 
+// Implement Transformable for PointCloud
+impl Transformable for PointCloud {
+    /// Transforms the point positions and normals; `xform` is left unchanged.
+    fn transform(&mut self, xform: &Xform) {
+        for p in &mut self.points {
+            p.transform(xform);
+        }
+        let normal_xform = xform.normal_matrix();
+        for n in &mut self.normals {
+            *n = normal_xform.transform_vector(n);
+            n.unitize();
+        }
+    }
+}
+
 impl fmt::Display for PointCloud {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -210,6 +302,7 @@ impl HasJsonData for PointCloud {
     fn to_json_data(&self, minimal: bool) -> serde_json::Value {
         let geometric_data = serde_json::json!({
             "points": self.points,
+            "normals": self.normals,
             "colors": self.colors,
             "xform": self.xform
         });
@@ -0,0 +1,115 @@
+use crate::geometry::{Mesh, Plane, Point, Vector};
+use crate::primitives::{Transformable, Xform};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A half-infinite line used for picking and intersection queries.
+///
+/// The direction does not need to be unit length; intersection results are reported
+/// as points, and `at` evaluates `origin + t * direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ray {
+    /// The start point of the ray.
+    pub origin: Point,
+    /// The direction of the ray.
+    pub direction: Vector,
+}
+
+impl Ray {
+    /// Creates a new `Ray`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The start point.
+    /// * `direction` - The direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Ray, Vector};
+    /// let ray = Ray::new(Point::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, -1.0));
+    /// assert_eq!(ray.origin.z, 1.0);
+    /// ```
+    pub fn new(origin: Point, direction: Vector) -> Self {
+        Ray { origin, direction }
+    }
+
+    /// Point at parameter `t` along the ray.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Ray, Vector};
+    /// let ray = Ray::new(Point::new(1.0, 0.0, 0.0), Vector::new(0.0, 2.0, 0.0));
+    /// assert_eq!(ray.at(1.5), Point::new(1.0, 3.0, 0.0));
+    /// ```
+    pub fn at(&self, t: f32) -> Point {
+        Point::new(
+            self.origin.x + t * self.direction.x,
+            self.origin.y + t * self.direction.y,
+            self.origin.z + t * self.direction.z,
+        )
+    }
+
+    /// Intersect the ray with a plane.
+    ///
+    /// # Returns
+    ///
+    /// The hit point, or None if the ray is parallel to the plane or points away from it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Plane, Point, Ray, Vector};
+    /// let ray = Ray::new(Point::new(1.0, 2.0, 5.0), Vector::new(0.0, 0.0, -1.0));
+    /// assert_eq!(ray.intersect_plane(&Plane::default()), Some(Point::new(1.0, 2.0, 0.0)));
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<Point> {
+        let denom = self.direction.dot(&plane.zaxis);
+        if denom.abs() < 1e-12 {
+            return None;
+        }
+        let to_origin = Vector::new(
+            plane.origin.x - self.origin.x,
+            plane.origin.y - self.origin.y,
+            plane.origin.z - self.origin.z,
+        );
+        let t = to_origin.dot(&plane.zaxis) / denom;
+        if t < 0.0 {
+            return None;
+        }
+        Some(self.at(t))
+    }
+
+    /// Intersect the ray with a mesh, see `Mesh::ray_intersect`.
+    ///
+    /// # Returns
+    ///
+    /// The nearest hit point and the key of the face that was hit, or None if the ray misses.
+    pub fn intersect_mesh(&self, mesh: &Mesh) -> Option<(Point, usize)> {
+        let (t, face) = mesh.ray_intersect(&self.origin, &self.direction)?;
+        Some((self.at(t), face))
+    }
+}
+
+// Implement Transformable for Ray
+impl Transformable for Ray {
+    fn transform(&mut self, xform: &Xform) {
+        self.origin = xform.transform_point(&self.origin);
+        self.direction = xform.transform_vector(&self.direction);
+    }
+}
+
+impl fmt::Display for Ray {
+    /// Log ray.
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::geometry::{Point, Ray, Vector};
+    /// let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+    /// println!("{}", ray);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ray {{ origin: {}, direction: {} }}", self.origin, self.direction)
+    }
+}
@@ -3,12 +3,15 @@
 mod macros;
 
 pub mod common;
+pub mod error;
 pub mod geometry;
 pub mod primitives;
+pub mod prelude;
 
+pub use error::Error;
 
 use geometry::{Point, Vector, Line, Arrow, Plane, Color, PointCloud, LineCloud, Pline, Mesh};
-use primitives::Xform;
+use primitives::{Transformable, Xform};
 use common::{JsonSerializable, FromJsonData};
 use serde::{Serialize, Deserialize};
 
@@ -41,6 +44,38 @@ pub struct AllGeometryData {
     pub sphere_mesh_index: Option<usize>,
 }
 
+impl AllGeometryData {
+    /// Apply a transformation to every geometry in the scene, in place.
+    ///
+    /// Vectors are transformed as directions. Mesh instance transforms are
+    /// pre-multiplied by `xform` instead of baking the instanced meshes.
+    /// Standalone `xforms` and `colors` are left unchanged.
+    pub fn transform(&mut self, xform: &Xform) {
+        for p in &mut self.points { p.transform(xform); }
+        for v in &mut self.vectors { v.transform(xform); }
+        for l in &mut self.lines { l.transform(xform); }
+        for a in &mut self.arrows { a.transform(xform); }
+        for p in &mut self.planes { p.transform(xform); }
+        for pc in &mut self.point_clouds { pc.transform(xform); }
+        for lc in &mut self.line_clouds { lc.transform(xform); }
+        for pl in &mut self.plines { pl.transform(xform); }
+
+        // Instanced meshes are positioned by their instance transforms only
+        let instanced: std::collections::HashSet<usize> =
+            self.mesh_instances.iter().map(|mi| mi.mesh_index).collect();
+        for (i, m) in self.meshes.iter_mut().enumerate() {
+            if !instanced.contains(&i) {
+                m.transform(xform);
+            }
+        }
+        for mi in &mut self.mesh_instances {
+            for t in &mut mi.transforms {
+                *t = xform * &*t;
+            }
+        }
+    }
+}
+
 // Implement JsonSerializable for AllGeometryData to work with json_dump/json_load
 impl JsonSerializable for AllGeometryData {
     fn to_json_value(&self) -> serde_json::Value {
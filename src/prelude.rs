@@ -0,0 +1,25 @@
+//! Convenience re-exports of the most commonly used types, traits, macros and
+//! JSON helpers.
+//!
+//! The `line!` macro is not included because a glob import of it would be ambiguous
+//! with `std::line!`; import it explicitly with `use openmodel::line;`.
+//!
+//! ```
+//! use openmodel::prelude::*;
+//!
+//! let p = Point::new(1.0, 2.0, 3.0);
+//! let q = point![4.0, 5.0, 6.0];
+//! assert_eq!(p.distance(&q), 27f32.sqrt());
+//!
+//! let path = std::env::temp_dir().join("openmodel_prelude_doctest.json");
+//! json_dump(&Line::from_points(&p, &q), path.to_str().unwrap()).unwrap();
+//! # let _ = std::fs::remove_file(path);
+//! ```
+
+pub use crate::primitives::{Color, Coords, Point, Quaternion, Transformable, Vector, Xform};
+pub use crate::geometry::{Arrow, Frame, Line, LineCloud, Mesh, Plane, Pline, PointCloud, Ray};
+pub use crate::common::{
+    json_dump, json_load, Data, FromJsonData, HasJsonData, JsonData, JsonSerializable,
+};
+pub use crate::{color, plane, point, vector, xform};
+pub use crate::Error;
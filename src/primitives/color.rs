@@ -183,6 +183,55 @@ impl Color {
         }
     }
 
+    /// Create an opaque color from hue, saturation and value.
+    ///
+    /// # Arguments
+    ///
+    /// * `h` - Hue in degrees, wrapped into 0.0-360.0 (0 = red, 120 = green, 240 = blue)
+    /// * `s` - Saturation (0.0-1.0)
+    /// * `v` - Value (0.0-1.0)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Color;
+    /// let green = Color::from_hsv(120.0, 1.0, 1.0);
+    /// assert_eq!((green.r, green.g, green.b, green.a), (0, 255, 0, 255));
+    /// ```
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+        let c = v * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = v - c;
+        Color::from_float(r + m, g + m, b + m, 1.0)
+    }
+
+    /// Create `n` distinct opaque colors with evenly spaced hues, starting at red,
+    /// at full saturation and value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Color;
+    /// let colors = Color::palette(3);
+    /// assert_eq!(colors, vec![Color::red(), Color::green(), Color::blue()]);
+    /// ```
+    pub fn palette(n: usize) -> Vec<Color> {
+        (0..n)
+            .map(|i| Color::from_hsv(360.0 * i as f32 / n as f32, 1.0, 1.0))
+            .collect()
+    }
+
     /// Create a color from a hexadecimal string representation.
     ///
     /// # Arguments
@@ -213,7 +262,12 @@ impl Color {
     pub fn from_hex(hex: &str) -> Option<Self> {
         // Remove leading # if present
         let hex = hex.strip_prefix('#').unwrap_or(hex);
-        
+
+        // Byte slicing below needs single-byte characters
+        if !hex.is_ascii() {
+            return None;
+        }
+
         // Parse RGB or RGBA
         match hex.len() {
             6 => {
@@ -259,6 +313,106 @@ impl Color {
             format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
         }
     }
+
+    /// Convert color to a JSON hex string value in the format "#RRGGBBAA".
+    ///
+    /// The regular serialization keeps the `{r, g, b, a}` struct form; this is an
+    /// opt-in alternative for consumers that expect hex strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Color;
+    /// let value = Color::new(255, 0, 0, 128).to_json_hex();
+    /// assert_eq!(value, serde_json::json!("#FF000080"));
+    /// ```
+    pub fn to_json_hex(&self) -> Value {
+        Value::String(self.to_hex(true))
+    }
+
+    /// Create a color from JSON in any supported form: the `{r, g, b, a}` struct,
+    /// the COMPAS-style `{dtype, data}` wrapper around it, or a hex string
+    /// ("#RRGGBB" or "#RRGGBBAA").
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Color;
+    /// let hex = Color::from_json(&serde_json::json!("#00FF0080")).unwrap();
+    /// assert_eq!(hex, Color::new(0, 255, 0, 128));
+    /// let plain = Color::from_json(&serde_json::json!({"r": 0, "g": 255, "b": 0, "a": 128})).unwrap();
+    /// assert_eq!(plain, hex);
+    /// ```
+    pub fn from_json(value: &Value) -> Option<Self> {
+        // Handle both COMPAS-style format and direct format
+        let color_data = value.get("data").unwrap_or(value);
+        if let Some(hex) = color_data.as_str() {
+            return Color::from_hex(hex);
+        }
+
+        let channel = |name: &str| color_data.get(name).and_then(|v| v.as_u64()).and_then(|v| u8::try_from(v).ok());
+        Some(Color::new(channel("r")?, channel("g")?, channel("b")?, channel("a")?))
+    }
+
+    /// Invert the RGB channels (255 minus each channel), preserving alpha.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Color;
+    /// assert_eq!(Color::red().invert(), Color::cyan());
+    /// ```
+    pub fn invert(&self) -> Self {
+        Color {
+            r: 255 - self.r,
+            g: 255 - self.g,
+            b: 255 - self.b,
+            a: self.a,
+        }
+    }
+
+    /// Return a copy of this color with a different alpha value.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - Alpha component (0-255)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Color;
+    /// let c = Color::red().with_alpha(128);
+    /// assert_eq!(c, Color::new(255, 0, 0, 128));
+    /// ```
+    pub fn with_alpha(&self, a: u8) -> Self {
+        Color { a, ..*self }
+    }
+
+    /// Linearly interpolate between this color and another, including alpha.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color reached at `t = 1.0`
+    /// * `t` - Interpolation parameter, clamped to 0.0-1.0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Color;
+    /// let mid = Color::black().lerp(&Color::white(), 0.5);
+    /// assert_eq!(mid.r, 128);
+    /// assert_eq!(mid.a, 255);
+    /// ```
+    pub fn lerp(&self, other: &Color, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Color {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+            a: mix(self.a, other.a),
+        }
+    }
 }
 
 // Implement Display
@@ -307,23 +461,7 @@ impl HasJsonData for Color {
 
 impl FromJsonData for Color {
     fn from_json_data(data: &Value) -> Option<Self> {
-        // Handle both COMPAS-style format and direct format
-        let color_data = if let Some(data_field) = data.get("data") {
-            data_field // COMPAS-style format
-        } else {
-            data // Direct format
-        };
-        
-        if let (Some(r), Some(g), Some(b), Some(a)) = (
-            color_data.get("r").and_then(|v| v.as_u64()).map(|v| v as u8),
-            color_data.get("g").and_then(|v| v.as_u64()).map(|v| v as u8),
-            color_data.get("b").and_then(|v| v.as_u64()).map(|v| v as u8),
-            color_data.get("a").and_then(|v| v.as_u64()).map(|v| v as u8)
-        ) {
-            Some(Color::new(r, g, b, a))
-        } else {
-            None
-        }
+        Color::from_json(data)
     }
 }
 
@@ -0,0 +1,36 @@
+use crate::primitives::{Point, Vector};
+
+/// Read-only access to three coordinates, usable through trait objects.
+///
+/// Coordinates are widened to f64 so generic algorithms can accumulate
+/// without losing precision.
+///
+/// # Example
+///
+/// ```
+/// use openmodel::primitives::{Coords, Point, Vector};
+/// let p = Point::new(0.0, 0.0, 0.0);
+/// let v = Vector::new(2.0, 4.0, 6.0);
+/// let items: Vec<&dyn Coords> = vec![&p, &v];
+/// let sum = items.iter().fold([0.0; 3], |acc, c| {
+///     let p = c.coords();
+///     [acc[0] + p[0], acc[1] + p[1], acc[2] + p[2]]
+/// });
+/// assert_eq!(sum, [2.0, 4.0, 6.0]);
+/// ```
+pub trait Coords {
+    /// The x, y and z coordinates.
+    fn coords(&self) -> [f64; 3];
+}
+
+impl Coords for Point {
+    fn coords(&self) -> [f64; 3] {
+        [self.x as f64, self.y as f64, self.z as f64]
+    }
+}
+
+impl Coords for Vector {
+    fn coords(&self) -> [f64; 3] {
+        [self.x as f64, self.y as f64, self.z as f64]
+    }
+}
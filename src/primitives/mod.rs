@@ -1,15 +1,23 @@
 //! Primitive geometric types without metadata
 //!
 //! Only includes types that cannot be visualized and therefore don't need Data fields.
+//!
+//! This module is the canonical home of `Point`, `Vector`, `Color` and `Xform`.
+//! `openmodel::geometry` re-exports the same types, so both paths name one type
+//! and share the same `Display` and `Serialize` output.
 
 pub mod vector;
 pub mod color;
 pub mod xform;
 pub mod point;
 pub mod quaternion;
+pub mod transformable;
+pub mod coords;
 
 pub use vector::Vector;
 pub use color::Color;
 pub use xform::Xform;
 pub use point::Point;
 pub use quaternion::Quaternion;
+pub use transformable::Transformable;
+pub use coords::Coords;
@@ -35,6 +35,19 @@ impl Point {
         Point { x, y, z }
     }
 
+    /// Creates a point at the world origin (0, 0, 0).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Point;
+    /// let p = Point::origin();
+    /// assert_eq!(p, Point::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn origin() -> Self {
+        Point::new(0.0, 0.0, 0.0)
+    }
+
     /// Computes the distance between two points.
     ///
     /// # Arguments
@@ -54,6 +67,152 @@ impl Point {
         ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2))
             .sqrt()
     }
+
+    /// Computes the point halfway between this point and another.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Point;
+    /// let mid = Point::new(0.0, 0.0, 0.0).midpoint(&Point::new(2.0, 4.0, 6.0));
+    /// assert_eq!(mid, Point::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn midpoint(&self, other: &Point) -> Point {
+        Point::new(
+            (self.x + other.x) * 0.5,
+            (self.y + other.y) * 0.5,
+            (self.z + other.z) * 0.5,
+        )
+    }
+
+    /// Computes the component-wise mean of a set of points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to average.
+    ///
+    /// # Returns
+    ///
+    /// The centroid, or None for an empty slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Point;
+    /// let square = [
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(1.0, 0.0, 0.0),
+    ///     Point::new(1.0, 1.0, 0.0),
+    ///     Point::new(0.0, 1.0, 0.0),
+    /// ];
+    /// assert_eq!(Point::centroid(&square), Some(Point::new(0.5, 0.5, 0.0)));
+    /// assert_eq!(Point::centroid(&[]), None);
+    /// ```
+    pub fn centroid(points: &[Point]) -> Option<Point> {
+        if points.is_empty() {
+            return None;
+        }
+        let n = points.len() as f32;
+        Some(Point::new(
+            points.iter().map(|p| p.x).sum::<f32>() / n,
+            points.iter().map(|p| p.y).sum::<f32>() / n,
+            points.iter().map(|p| p.z).sum::<f32>() / n,
+        ))
+    }
+
+    /// Linearly interpolates between this point and another: `self + (other - self) * t`.
+    ///
+    /// `t` is not clamped, so values outside [0, 1] extrapolate along the line.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The point reached at `t = 1`.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Point;
+    /// let mid = Point::new(0.0, 0.0, 0.0).lerp(&Point::new(2.0, 4.0, 6.0), 0.5);
+    /// assert_eq!(mid, Point::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn lerp(&self, other: &Point, t: f32) -> Point {
+        Point::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.z + (other.z - self.z) * t,
+        )
+    }
+
+    /// Computes the barycentric coordinates of `p` with respect to the triangle `a`, `b`, `c`.
+    /// Points off the triangle plane are projected onto it.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The point to express in barycentric coordinates.
+    /// * `a`, `b`, `c` - The triangle corners.
+    ///
+    /// # Returns
+    ///
+    /// The weights `(u, v, w)` with `p = u * a + v * b + w * c` and `u + v + w = 1`,
+    /// or `(0.0, 0.0, 0.0)` for a degenerate triangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Point;
+    /// let a = Point::new(0.0, 0.0, 0.0);
+    /// let b = Point::new(1.0, 0.0, 0.0);
+    /// let c = Point::new(0.0, 1.0, 0.0);
+    /// let (u, v, w) = Point::barycentric(&b, &a, &b, &c);
+    /// assert_eq!((u, v, w), (0.0, 1.0, 0.0));
+    /// ```
+    pub fn barycentric(p: &Point, a: &Point, b: &Point, c: &Point) -> (f32, f32, f32) {
+        let v0 = [b.x - a.x, b.y - a.y, b.z - a.z];
+        let v1 = [c.x - a.x, c.y - a.y, c.z - a.z];
+        let v2 = [p.x - a.x, p.y - a.y, p.z - a.z];
+        let dot = |l: &[f32; 3], r: &[f32; 3]| l[0] * r[0] + l[1] * r[1] + l[2] * r[2];
+
+        let d00 = dot(&v0, &v0);
+        let d01 = dot(&v0, &v1);
+        let d11 = dot(&v1, &v1);
+        let d20 = dot(&v2, &v0);
+        let d21 = dot(&v2, &v1);
+        let denom = d00 * d11 - d01 * d01;
+        if denom.abs() <= f32::EPSILON * d00 * d11 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        (1.0 - v - w, v, w)
+    }
+
+    /// Checks whether `p` lies inside the triangle `a`, `b`, `c` (edges included).
+    /// Degenerate triangles contain no points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Point;
+    /// let a = Point::new(0.0, 0.0, 0.0);
+    /// let b = Point::new(1.0, 0.0, 0.0);
+    /// let c = Point::new(0.0, 1.0, 0.0);
+    /// assert!(Point::in_triangle(&Point::new(0.25, 0.25, 0.0), &a, &b, &c));
+    /// assert!(!Point::in_triangle(&Point::new(1.0, 1.0, 0.0), &a, &b, &c));
+    /// ```
+    pub fn in_triangle(p: &Point, a: &Point, b: &Point, c: &Point) -> bool {
+        let (u, v, w) = Point::barycentric(p, a, b, c);
+        if u == 0.0 && v == 0.0 && w == 0.0 {
+            return false;
+        }
+        let tol = 1e-6;
+        [u, v, w].iter().all(|x| *x >= -tol && *x <= 1.0 + tol)
+    }
 }
 
 impl Default for Point {
@@ -535,7 +694,7 @@ impl fmt::Display for Point {
     /// ```
     /// use openmodel::primitives::Point;
     /// let p = Point::new(0.0, 0.0, 1.0);
-    /// println!("{}", p);
+    /// assert_eq!(format!("{}", p), "Point(0, 0, 1)");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Point({}, {}, {})", self.x, self.y, self.z)
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::fmt;
 
-use crate::primitives::Vector;
+use crate::primitives::{Vector, Xform};
 
 /// A quaternion in scalar/vector form for 3D rotations.
 /// 
@@ -90,7 +90,7 @@ impl Quaternion {
     ///
     /// # Arguments
     ///
-    /// * `axis` - The rotation axis (should be normalized)
+    /// * `axis` - The rotation axis, normalized internally; a zero axis gives the identity
     /// * `angle` - The rotation angle in radians
     ///
     /// # Example
@@ -98,13 +98,18 @@ impl Quaternion {
     /// ```
     /// use openmodel::primitives::{Quaternion, Vector};
     /// use std::f32::consts::PI;
-    /// let axis = Vector::new(0.0, 0.0, 1.0); // Z-axis
+    /// let axis = Vector::new(0.0, 0.0, 2.0); // Z-axis, any length
     /// let q = Quaternion::from_axis_angle(axis, PI / 2.0); // 90 degree rotation
+    /// assert!((q.magnitude() - 1.0).abs() < 1e-6);
     /// ```
     pub fn from_axis_angle(axis: Vector, angle: f32) -> Quaternion {
+        let length = axis.length();
+        if length == 0.0 {
+            return Quaternion::identity();
+        }
         let half_angle = angle * 0.5;
         let (sin_half, cos_half) = half_angle.sin_cos();
-        Quaternion::from_sv(cos_half, axis * sin_half)
+        Quaternion::from_sv(cos_half, axis * (sin_half / length))
     }
 
     /// Create a quaternion representing the rotation from one vector to another.
@@ -214,6 +219,29 @@ impl Quaternion {
         }
     }
 
+    /// Normalize the quaternion to unit length in-place.
+    ///
+    /// Returns `false` and leaves the quaternion unchanged if its magnitude is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Quaternion;
+    /// let mut q = Quaternion::new(0.0, 0.0, 0.0, 3.0);
+    /// assert!(q.normalize_mut());
+    /// assert_eq!(q.v.z, 1.0);
+    /// assert!(!Quaternion::zero().normalize_mut());
+    /// ```
+    pub fn normalize_mut(&mut self) -> bool {
+        let mag = self.magnitude();
+        if mag > 0.0 {
+            *self /= mag;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Compute the conjugate of the quaternion.
     ///
     /// The conjugate of a quaternion q = (w, x, y, z) is q* = (w, -x, -y, -z).
@@ -270,7 +298,8 @@ impl Quaternion {
     /// let q2 = Quaternion::new(0.0, 1.0, 0.0, 0.0);
     /// let result = q1.nlerp(&q2, 0.5);
     /// ```
-    pub fn nlerp(&self, mut other: Quaternion, t: f32) -> Quaternion {
+    pub fn nlerp(&self, other: &Quaternion, t: f32) -> Quaternion {
+        let mut other = *other;
         if self.dot(&other) < 0.0 {
             other = -other;
         }
@@ -280,6 +309,8 @@ impl Quaternion {
     /// Spherical linear interpolation between two quaternions.
     ///
     /// This provides the smoothest interpolation but is more expensive than nlerp.
+    /// Both endpoints are normalized first and the shorter arc is taken; nearly
+    /// parallel quaternions fall back to nlerp.
     ///
     /// # Arguments
     ///
@@ -294,8 +325,10 @@ impl Quaternion {
     /// let q2 = Quaternion::new(0.0, 1.0, 0.0, 0.0);
     /// let result = q1.slerp(&q2, 0.5);
     /// ```
-    pub fn slerp(&self, mut other: Quaternion, t: f32) -> Quaternion {
-        let mut dot = self.dot(&other);
+    pub fn slerp(&self, other: &Quaternion, t: f32) -> Quaternion {
+        let start = self.normalize();
+        let mut other = other.normalize();
+        let mut dot = start.dot(&other);
         const DOT_THRESHOLD: f32 = 0.9995;
 
         if dot < 0.0 {
@@ -305,7 +338,7 @@ impl Quaternion {
 
         // If quaternions are close together, use nlerp
         if dot > DOT_THRESHOLD {
-            self.nlerp(other, t)
+            start.nlerp(&other, t)
         } else {
             // Stay within the domain of acos()
             let robust_dot = dot.min(1.0).max(-1.0);
@@ -314,7 +347,7 @@ impl Quaternion {
             let scale1 = (theta * (1.0 - t)).sin();
             let scale2 = (theta * t).sin();
 
-            (*self * scale1 + other * scale2).normalize()
+            (start * scale1 + other * scale2).normalize()
         }
     }
 
@@ -340,6 +373,39 @@ impl Quaternion {
         *vec + (self.v.cross(&tmp) * two)
     }
 
+    /// Convert the quaternion to the equivalent rotation matrix.
+    ///
+    /// The quaternion is normalized first, so any non-zero quaternion gives a pure rotation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Point, Quaternion, Vector};
+    /// use std::f32::consts::PI;
+    /// let q = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), PI / 2.0);
+    /// let p = q.to_xform().transform_point(&Point::new(1.0, 0.0, 0.0));
+    /// assert!(p.x.abs() < 1e-6 && (p.y - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn to_xform(&self) -> Xform {
+        let q = self.normalize();
+        let (w, x, y, z) = (q.s, q.v.x, q.v.y, q.v.z);
+
+        let mut xform = Xform::identity();
+        xform[(0, 0)] = 1.0 - 2.0 * (y * y + z * z);
+        xform[(0, 1)] = 2.0 * (x * y - w * z);
+        xform[(0, 2)] = 2.0 * (x * z + w * y);
+
+        xform[(1, 0)] = 2.0 * (x * y + w * z);
+        xform[(1, 1)] = 1.0 - 2.0 * (x * x + z * z);
+        xform[(1, 2)] = 2.0 * (y * z - w * x);
+
+        xform[(2, 0)] = 2.0 * (x * z - w * y);
+        xform[(2, 1)] = 2.0 * (y * z + w * x);
+        xform[(2, 2)] = 1.0 - 2.0 * (x * x + y * y);
+
+        xform
+    }
+
     /// Check if the quaternion has finite components.
     ///
     /// # Example
@@ -476,7 +542,7 @@ impl Quaternion {
     /// # Arguments
     ///
     /// * Matrix elements in row-major order
-    fn from_rotation_matrix(
+    pub(crate) fn from_rotation_matrix(
         m00: f32, m01: f32, m02: f32,
         m10: f32, m11: f32, m12: f32,
         m20: f32, m21: f32, m22: f32,
@@ -581,6 +647,15 @@ impl Mul<Quaternion> for Quaternion {
 
     /// Multiply two quaternions (quaternion composition).
     fn mul(self, other: Quaternion) -> Quaternion {
+        &self * &other
+    }
+}
+
+impl Mul<&Quaternion> for &Quaternion {
+    type Output = Quaternion;
+
+    /// Hamilton product: `a * b` applies `b` first, then `a`.
+    fn mul(self, other: &Quaternion) -> Quaternion {
         Quaternion::new(
             self.s * other.s - self.v.x * other.v.x - self.v.y * other.v.y - self.v.z * other.v.z,
             self.s * other.v.x + self.v.x * other.s + self.v.y * other.v.z - self.v.z * other.v.y,
@@ -773,7 +848,7 @@ mod tests {
         let q1 = Quaternion::identity();
         let q2 = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), PI / 2.0);
         
-        let result = q1.slerp(q2, 0.5);
+        let result = q1.slerp(&q2, 0.5);
         
         // Should be halfway between identity and 90-degree rotation
         let expected = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), PI / 4.0);
@@ -0,0 +1,40 @@
+use crate::primitives::{Point, Vector, Xform};
+
+/// Types that can be transformed in place by an `Xform`.
+///
+/// Positions are transformed as points (translation applies), directions as
+/// vectors (translation ignored).
+pub trait Transformable {
+    /// Apply the transformation in place.
+    fn transform(&mut self, xform: &Xform);
+
+    /// Return a transformed copy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Point, Transformable, Xform};
+    /// let p = Point::new(1.0, 0.0, 0.0).transformed(&Xform::translation(0.0, 2.0, 0.0));
+    /// assert_eq!(p.y, 2.0);
+    /// ```
+    fn transformed(&self, xform: &Xform) -> Self
+    where
+        Self: Sized + Clone,
+    {
+        let mut out = self.clone();
+        out.transform(xform);
+        out
+    }
+}
+
+impl Transformable for Point {
+    fn transform(&mut self, xform: &Xform) {
+        *self = xform.transform_point(self);
+    }
+}
+
+impl Transformable for Vector {
+    fn transform(&mut self, xform: &Xform) {
+        *self = xform.transform_vector(self);
+    }
+}
@@ -103,6 +103,19 @@ impl Vector {
         Self::new(0.0, 0.0, 0.0)
     }
 
+    /// Creates a vector with all components set to one (1, 1, 1).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let v = Vector::one();
+    /// assert_eq!(v, Vector::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn one() -> Self {
+        Self::new(1.0, 1.0, 1.0)
+    }
+
     /// Creates a unit vector in the x direction.
     pub fn unit_x() -> Self {
         Self::new(1.0, 0.0, 0.0)
@@ -188,6 +201,140 @@ impl Vector {
         }
     }
 
+    /// Calculate the unsigned angle between this vector and another, in radians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other vector.
+    ///
+    /// # Returns
+    ///
+    /// The angle in [0, π], or 0.0 if either vector has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let angle = Vector::new(1.0, 0.0, 0.0).angle(&Vector::new(0.0, 2.0, 0.0));
+    /// assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn angle(&self, other: &Vector) -> f32 {
+        let lengths = self.length() * other.length();
+        if lengths == 0.0 {
+            return 0.0;
+        }
+        (self.dot(other) / lengths).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Calculate the signed angle from this vector to another, in radians.
+    ///
+    /// The angle is positive when the rotation from `self` to `other` is
+    /// counter-clockwise looking down `normal`, so reversing `normal` flips the sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other vector.
+    /// * `normal` - The reference normal that fixes the orientation.
+    ///
+    /// # Returns
+    ///
+    /// The angle in [-π, π], or 0.0 if either vector has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let x = Vector::new(1.0, 0.0, 0.0);
+    /// let y = Vector::new(0.0, 1.0, 0.0);
+    /// let z = Vector::new(0.0, 0.0, 1.0);
+    /// assert!((x.angle_signed(&y, &z) - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// assert!((y.angle_signed(&x, &z) + std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn angle_signed(&self, other: &Vector, normal: &Vector) -> f32 {
+        let angle = self.angle(other);
+        if self.cross(other).dot(normal) < 0.0 {
+            -angle
+        } else {
+            angle
+        }
+    }
+
+    /// Reflect this vector off a surface with the given normal: `v - 2 (v·n̂) n̂`.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - The surface normal, normalized internally.
+    ///
+    /// # Returns
+    ///
+    /// The reflected vector, or an unchanged copy if `normal` has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let r = Vector::new(1.0, -1.0, 0.0).reflect(&Vector::new(0.0, 2.0, 0.0));
+    /// assert_eq!((r.x, r.y, r.z), (1.0, 1.0, 0.0));
+    /// ```
+    pub fn reflect(&self, normal: &Vector) -> Vector {
+        let mut n = *normal;
+        if !n.unitize() {
+            return *self;
+        }
+        *self - n * (2.0 * self.dot(&n))
+    }
+
+    /// Rotate this vector about an axis through the origin using Rodrigues' formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The rotation axis, normalized internally.
+    /// * `angle` - The rotation angle in radians, counter-clockwise looking down the axis.
+    ///
+    /// # Returns
+    ///
+    /// The rotated vector, or an unchanged copy if `axis` has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let v = Vector::new(1.0, 0.0, 0.0).rotate_around_axis(&Vector::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+    /// assert!(v.x.abs() < 1e-6 && (v.y - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn rotate_around_axis(&self, axis: &Vector, angle: f32) -> Vector {
+        let mut k = *axis;
+        if !k.unitize() {
+            return *self;
+        }
+        let (sin, cos) = angle.sin_cos();
+        *self * cos + k.cross(self) * sin + k * (k.dot(self) * (1.0 - cos))
+    }
+
+    /// Linearly interpolates between this vector and another: `self + (other - self) * t`.
+    ///
+    /// `t` is not clamped, so values outside [0, 1] extrapolate.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The vector reached at `t = 1`.
+    /// * `t` - The interpolation parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let mid = Vector::new(1.0, 0.0, 0.0).lerp(&Vector::new(0.0, 1.0, 0.0), 0.5);
+    /// assert_eq!((mid.x, mid.y, mid.z), (0.5, 0.5, 0.0));
+    /// ```
+    pub fn lerp(&self, other: &Vector, t: f32) -> Vector {
+        Vector {
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+            z: self.z + (other.z - self.z) * t,
+        }
+    }
+
     /// Calculate the length (magnitude) of this vector.
     ///
     /// # Example
@@ -276,6 +423,80 @@ impl Vector {
         self.z /= length;
         true
     }
+
+    /// Returns a unit-length copy of the vector, leaving `self` unchanged.
+    ///
+    /// Unlike `normalize`, a vector too small to unitize does not panic and maps
+    /// to the zero vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let v = Vector::new(0.0, 3.0, 4.0).unitized();
+    /// assert_eq!((v.x, v.y, v.z), (0.0, 0.6, 0.8));
+    /// let zero = Vector::new(0.0, 0.0, 0.0).unitized();
+    /// assert_eq!(zero.length(), 0.0);
+    /// ```
+    pub fn unitized(&self) -> Vector {
+        let mut v = *self;
+        if v.unitize() {
+            v
+        } else {
+            Vector::new(0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Create a vector from spherical coordinates.
+    ///
+    /// `theta` is the inclination (polar angle) in radians measured from +Z, and `phi`
+    /// the azimuth in radians measured in the XY plane from +X towards +Y.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Vector;
+    /// let v = Vector::from_spherical(2.0, std::f32::consts::FRAC_PI_2, 0.0);
+    /// assert!((v.x - 2.0).abs() < 1e-6 && v.y.abs() < 1e-6 && v.z.abs() < 1e-6);
+    /// ```
+    pub fn from_spherical(radius: f32, theta: f32, phi: f32) -> Self {
+        Vector {
+            x: radius * theta.sin() * phi.cos(),
+            y: radius * theta.sin() * phi.sin(),
+            z: radius * theta.cos(),
+        }
+    }
+
+    /// Convert to spherical coordinates `(radius, inclination, azimuth)`, see
+    /// `from_spherical` for the angle conventions.
+    ///
+    /// The inclination is in `[0, π]` and the azimuth in `(-π, π]`; the zero vector
+    /// returns `(0, 0, 0)`.
+    pub fn to_spherical(&self) -> (f32, f32, f32) {
+        let radius = self.length();
+        if radius == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+        let theta = (self.z / radius).clamp(-1.0, 1.0).acos();
+        let phi = self.y.atan2(self.x);
+        (radius, theta, phi)
+    }
+
+    /// Create a vector from cylindrical coordinates: the `radius` from the Z axis,
+    /// the azimuth `phi` in radians from +X towards +Y, and the height `z`.
+    pub fn from_cylindrical(radius: f32, phi: f32, z: f32) -> Self {
+        Vector {
+            x: radius * phi.cos(),
+            y: radius * phi.sin(),
+            z,
+        }
+    }
+
+    /// Convert to cylindrical coordinates `(radius, azimuth, z)`, see `from_cylindrical`.
+    /// The azimuth is in `(-π, π]`.
+    pub fn to_cylindrical(&self) -> (f32, f32, f32) {
+        (self.x.hypot(self.y), self.y.atan2(self.x), self.z)
+    }
 }
 
 // Implement Display
@@ -311,6 +532,15 @@ impl Add for &Vector {
     }
 }
 
+// Implement Add for Vector + &Vector = Vector
+impl Add<&Vector> for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: &Vector) -> Self::Output {
+        self + *rhs
+    }
+}
+
 // Implement AddAssign for Vector += Vector
 impl AddAssign for Vector {
     fn add_assign(&mut self, rhs: Self) {
@@ -346,6 +576,15 @@ impl Sub for &Vector {
     }
 }
 
+// Implement Sub for Vector - &Vector = Vector
+impl Sub<&Vector> for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: &Vector) -> Self::Output {
+        self - *rhs
+    }
+}
+
 // Implement SubAssign for Vector -= Vector
 impl SubAssign for Vector {
     fn sub_assign(&mut self, rhs: Self) {
@@ -390,6 +629,24 @@ impl MulAssign<f32> for Vector {
     }
 }
 
+// Implement Mul for f32 * Vector = Vector
+impl Mul<Vector> for f32 {
+    type Output = Vector;
+
+    fn mul(self, vector: Vector) -> Self::Output {
+        vector * self
+    }
+}
+
+// Implement Mul for f32 * &Vector = Vector
+impl Mul<&Vector> for f32 {
+    type Output = Vector;
+
+    fn mul(self, vector: &Vector) -> Self::Output {
+        vector * self
+    }
+}
+
 // Implement Div for Vector / f64 = Vector
 impl Div<f32> for Vector {
     type Output = Vector;
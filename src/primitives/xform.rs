@@ -1,5 +1,6 @@
 use crate::primitives::vector::Vector;
 use crate::primitives::point::Point;
+use crate::primitives::quaternion::Quaternion;
 use serde::{Deserialize, Serialize, Serializer};
 use std::ops::{Index, IndexMut, Mul, MulAssign};
 use std::fmt;
@@ -8,6 +9,8 @@ use serde_json::Value;
 
 /// A 4x4 transformation matrix in 3D space
 /// Stored in column-major order (standard in graphics)
+///
+/// This is the crate's only 4x4 matrix type; there is no separate `Matrix`.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Xform {
     /// The matrix elements stored in column-major order as a flattened array
@@ -15,6 +18,24 @@ pub struct Xform {
 }
 
 impl Xform {
+    /// The identity transformation as a constant, usable in `const` and `static` contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Xform;
+    /// const ID: Xform = Xform::IDENTITY;
+    /// assert_eq!(ID.m, Xform::identity().m);
+    /// ```
+    pub const IDENTITY: Xform = Xform {
+        m: [
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ],
+    };
+
     /// Creates a new transformation matrix with all elements set to the given value.
     ///
     /// # Arguments
@@ -60,12 +81,7 @@ impl Xform {
     /// assert_eq!(xform[(0, 1)], 0.0);
     /// ```
     pub fn identity() -> Self {
-        let mut xform = Xform::new(0.0);
-        xform.m[0] = 1.0;
-        xform.m[5] = 1.0;
-        xform.m[10] = 1.0;
-        xform.m[15] = 1.0;
-        xform
+        Self::IDENTITY
     }
 
     /// Creates a new translation transformation matrix.
@@ -174,15 +190,29 @@ impl Xform {
         xform
     }
 
-    /// Creates a new rotation transformation matrix around an arbitrary axis.
+    /// Creates a new rotation transformation matrix around an arbitrary axis
+    /// through the origin, using Rodrigues' formula.
     ///
     /// # Arguments
     ///
-    /// * `axis` - The axis of rotation (should be normalized)
-    /// * `angle_radians` - Rotation angle in radians
+    /// * `axis` - The axis of rotation, normalized internally
+    /// * `angle_radians` - Rotation angle in radians, counter-clockwise looking down the axis
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Point, Vector, Xform};
+    /// let xform = Xform::rotation(&Vector::new(0.0, 0.0, 2.0), std::f32::consts::FRAC_PI_2);
+    /// let p = xform.transform_point(&Point::new(1.0, 0.0, 0.0));
+    /// assert!(p.x.abs() < 1e-6 && (p.y - 1.0).abs() < 1e-6);
+    /// ```
     pub fn rotation(axis: &Vector, angle_radians: f32) -> Self {
-        assert!((axis.length() - 1.0).abs() < 1e-6, "Axis must be normalized");
-        
+        let axis = axis.normalize();
+
         let mut xform = Self::identity();
         let cos_angle = angle_radians.cos();
         let sin_angle = angle_radians.sin();
@@ -210,6 +240,44 @@ impl Xform {
         xform
     }
 
+    /// Creates a reflection across the plane through `origin` with the given normal.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - Any point on the mirror plane
+    /// * `normal` - The plane normal, normalized internally
+    ///
+    /// # Returns
+    ///
+    /// The reflection, or the identity if `normal` has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Point, Vector, Xform};
+    /// let mirror = Xform::mirror(&Point::new(0.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0));
+    /// let p = mirror.transform_point(&Point::new(1.0, 2.0, 3.0));
+    /// assert_eq!((p.x, p.y, p.z), (1.0, 2.0, -3.0));
+    /// ```
+    pub fn mirror(origin: &Point, normal: &Vector) -> Self {
+        let mut n = *normal;
+        if !n.unitize() {
+            return Self::identity();
+        }
+        let d = -(n.x * origin.x + n.y * origin.y + n.z * origin.z);
+        let n = [n.x, n.y, n.z];
+
+        // p' = p - 2 (n . p + d) n
+        let mut xform = Self::identity();
+        for row in 0..3 {
+            for col in 0..3 {
+                xform[(row, col)] -= 2.0 * n[row] * n[col];
+            }
+            xform[(row, 3)] = -2.0 * d * n[row];
+        }
+        xform
+    }
+
     /// Creates a new transformation matrix that changes the basis from one coordinate system to another.
     ///
     /// # Arguments
@@ -244,6 +312,93 @@ impl Xform {
         xform
     }
 
+    /// Creates the transformation from world XY coordinates into a plane: the world
+    /// origin maps to `origin` and the world axes map to the plane axes.
+    /// Same matrix as `change_basis`.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the plane
+    /// * `x_axis`, `y_axis`, `z_axis` - The orthonormal axes of the plane
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Point, Vector, Xform};
+    /// let xform = Xform::xy_to_plane(&Point::new(0.0, 0.0, 5.0), &Vector::new(0.0, 1.0, 0.0), &Vector::new(-1.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0));
+    /// let p = xform.transform_point(&Point::new(1.0, 0.0, 0.0));
+    /// assert_eq!((p.x, p.y, p.z), (0.0, 1.0, 5.0));
+    /// ```
+    pub fn xy_to_plane(origin: &Point, x_axis: &Vector, y_axis: &Vector, z_axis: &Vector) -> Self {
+        Self::change_basis(origin, x_axis, y_axis, z_axis)
+    }
+
+    /// Creates the transformation from a plane into world XY coordinates, the inverse
+    /// of `xy_to_plane`: points on the plane end up at z = 0 with their (u, v)
+    /// coordinates as x and y.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The origin of the plane
+    /// * `x_axis`, `y_axis`, `z_axis` - The orthonormal axes of the plane
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Point, Vector, Xform};
+    /// let xform = Xform::plane_to_xy(&Point::new(0.0, 0.0, 5.0), &Vector::new(0.0, 1.0, 0.0), &Vector::new(-1.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0));
+    /// let p = xform.transform_point(&Point::new(0.0, 1.0, 5.0));
+    /// assert_eq!((p.x, p.y, p.z), (1.0, 0.0, 0.0));
+    /// ```
+    pub fn plane_to_xy(origin: &Point, x_axis: &Vector, y_axis: &Vector, z_axis: &Vector) -> Self {
+        let basis = Self::change_basis(origin, x_axis, y_axis, z_axis);
+        let m = &basis.m;
+        let mut xform = Self::identity();
+
+        // Transpose the rotation block
+        for r in 0..3 {
+            for c in 0..3 {
+                xform.m[c * 4 + r] = m[r * 4 + c];
+            }
+        }
+
+        // Translation is -R^T * origin
+        for r in 0..3 {
+            xform.m[12 + r] = -(xform.m[r] * origin.x + xform.m[4 + r] * origin.y + xform.m[8 + r] * origin.z);
+        }
+        xform
+    }
+
+    /// Creates a view transformation for a camera at `eye` looking at `target`.
+    ///
+    /// In view space the camera sits at the origin, looks down -Z and has `up`
+    /// (orthonormalized against the view direction) along +Y.
+    ///
+    /// # Arguments
+    ///
+    /// * `eye` - The camera position
+    /// * `target` - The point the camera looks at
+    /// * `up` - The approximate up direction
+    ///
+    /// # Panics
+    ///
+    /// Panics if `eye` equals `target` or `up` is parallel to the view direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Point, Vector, Xform};
+    /// let view = Xform::look_at(&Point::new(0.0, 0.0, 5.0), &Point::new(0.0, 0.0, 0.0), &Vector::new(0.0, 1.0, 0.0));
+    /// let p = view.transform_point(&Point::new(0.0, 0.0, 0.0));
+    /// assert_eq!((p.x, p.y, p.z), (0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at(eye: &Point, target: &Point, up: &Vector) -> Self {
+        let z_axis = Vector::new(eye.x - target.x, eye.y - target.y, eye.z - target.z).normalize();
+        let x_axis = up.cross(&z_axis).normalize();
+        let y_axis = z_axis.cross(&x_axis);
+        Self::plane_to_xy(eye, &x_axis, &y_axis, &z_axis)
+    }
+
     /// Returns the inverse of this transformation matrix.
     ///
     /// # Example
@@ -261,6 +416,12 @@ impl Xform {
     /// assert!((back.z - point.z).abs() < 1e-10);
     /// ```
     pub fn inverse(&self) -> Option<Xform> {
+        // Check if determinant is too close to zero
+        let det = self.determinant();
+        if det.abs() < 1e-10 {
+            return None;
+        }
+
         let mut result = Xform::new(0.0);
         
         // Compute the inverse using the adjugate and determinant
@@ -287,14 +448,6 @@ impl Xform {
         let c32 = -(m[0] * (m[5] * m[11] - m[7] * m[9]) - m[1] * (m[4] * m[11] - m[7] * m[8]) + m[3] * (m[4] * m[9] - m[5] * m[8]));
         let c33 = m[0] * (m[5] * m[10] - m[6] * m[9]) - m[1] * (m[4] * m[10] - m[6] * m[8]) + m[2] * (m[4] * m[9] - m[5] * m[8]);
         
-        // Calculate determinant
-        let det = m[0] * c00 + m[1] * c01 + m[2] * c02 + m[3] * c03;
-        
-        // Check if determinant is too close to zero
-        if det.abs() < 1e-10 {
-            return None;
-        }
-        
         // Calculate inverse
         let inv_det = 1.0 / det;
         
@@ -322,6 +475,58 @@ impl Xform {
         Some(result)
     }
 
+    /// Returns the determinant of the full 4x4 matrix.
+    ///
+    /// Zero means the transform is not invertible; a negative value means it mirrors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Xform;
+    /// assert_eq!(Xform::scaling(2.0, 3.0, 4.0).determinant(), 24.0);
+    /// assert!(Xform::scaling(-1.0, 1.0, 1.0).determinant() < 0.0);
+    /// ```
+    pub fn determinant(&self) -> f32 {
+        let m = &self.m;
+
+        // 2x2 minors of the first two and last two columns
+        let s0 = m[0] * m[5] - m[1] * m[4];
+        let s1 = m[0] * m[6] - m[2] * m[4];
+        let s2 = m[0] * m[7] - m[3] * m[4];
+        let s3 = m[1] * m[6] - m[2] * m[5];
+        let s4 = m[1] * m[7] - m[3] * m[5];
+        let s5 = m[2] * m[7] - m[3] * m[6];
+
+        let c5 = m[10] * m[15] - m[11] * m[14];
+        let c4 = m[9] * m[15] - m[11] * m[13];
+        let c3 = m[9] * m[14] - m[10] * m[13];
+        let c2 = m[8] * m[15] - m[11] * m[12];
+        let c1 = m[8] * m[14] - m[10] * m[12];
+        let c0 = m[8] * m[13] - m[9] * m[12];
+
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+
+    /// Returns the transpose of this matrix, swapping rows and columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Xform;
+    /// let t = Xform::translation(1.0, 2.0, 3.0).transpose();
+    /// assert_eq!(t[(3, 0)], 1.0);
+    /// assert_eq!(t[(0, 3)], 0.0);
+    /// ```
+    pub fn transpose(&self) -> Xform {
+        let mut result = Xform::new(0.0);
+        for row in 0..4 {
+            for col in 0..4 {
+                result[(row, col)] = self[(col, row)];
+            }
+        }
+        result
+    }
+
     /// Transforms a point by this transformation matrix.
     ///
     /// # Arguments
@@ -380,28 +585,182 @@ impl Xform {
         }
     }
 
-    /// Checks if this transform is the identity matrix.
+    /// Transforms a surface normal by the inverse-transpose of this matrix, so it
+    /// stays perpendicular to the transformed surface under non-uniform scaling.
+    /// Translation is ignored and the result is not normalized. A singular
+    /// matrix falls back to `transform_vector`.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - The normal to transform
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Xform, Vector};
+    /// // Stretching x flattens a 45 degree slope, so its normal tilts toward x
+    /// let transform = Xform::scaling(2.0, 1.0, 1.0);
+    /// let normal = transform.transform_normal(&Vector::new(1.0, 1.0, 0.0));
+    /// assert_eq!((normal.x, normal.y, normal.z), (0.5, 1.0, 0.0));
+    /// ```
+    pub fn transform_normal(&self, normal: &Vector) -> Vector {
+        self.normal_matrix().transform_vector(normal)
+    }
+
+    /// Returns the matrix that `transform_normal` applies: the inverse-transpose of
+    /// this matrix, or this matrix itself when it is singular. Compute it once and
+    /// use `transform_vector` when transforming many normals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::{Xform, Vector};
+    /// let transform = Xform::scaling(2.0, 1.0, 1.0);
+    /// let normals = transform.normal_matrix();
+    /// let n = normals.transform_vector(&Vector::new(1.0, 1.0, 0.0));
+    /// assert_eq!((n.x, n.y, n.z), (0.5, 1.0, 0.0));
+    /// ```
+    pub fn normal_matrix(&self) -> Xform {
+        match self.inverse() {
+            Some(inverse) => inverse.transpose(),
+            None => self.clone(),
+        }
+    }
+
+    /// Splits an affine transform `T * R * S` into its translation, rotation and
+    /// per-axis scale.
+    ///
+    /// A mirroring transform (negative determinant) is returned with a negative x scale.
+    /// If any axis is scaled to zero the rotation cannot be recovered and is the identity.
+    ///
+    /// # Returns
+    ///
+    /// `(translation, rotation, scale)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Xform;
+    /// let xform = Xform::translation(1.0, 2.0, 3.0) * Xform::scaling(2.0, 2.0, 2.0);
+    /// let (translation, rotation, scale) = xform.decompose();
+    /// assert_eq!((translation.x, translation.y, translation.z), (1.0, 2.0, 3.0));
+    /// assert_eq!((scale.x, scale.y, scale.z), (2.0, 2.0, 2.0));
+    /// assert_eq!(rotation.s, 1.0);
+    /// ```
+    pub fn decompose(&self) -> (Vector, Quaternion, Vector) {
+        let m = &self.m;
+        let translation = Vector::new(m[12], m[13], m[14]);
+
+        let col = |c: usize| Vector::new(m[c * 4], m[c * 4 + 1], m[c * 4 + 2]);
+        let (x, y, z) = (col(0), col(1), col(2));
+        let mut scale = Vector::new(x.length(), y.length(), z.length());
+        if x.cross(&y).dot(&z) < 0.0 {
+            scale.x = -scale.x;
+        }
+
+        if scale.x == 0.0 || scale.y == 0.0 || scale.z == 0.0 {
+            return (translation, Quaternion::identity(), scale);
+        }
+
+        let (x, y, z) = (x / scale.x, y / scale.y, z / scale.z);
+        let rotation = Quaternion::from_rotation_matrix(
+            x.x, y.x, z.x,
+            x.y, y.y, z.y,
+            x.z, y.z, z.z,
+        )
+        .normalize();
+        (translation, rotation, scale)
+    }
+
+    /// Checks if this transform is the identity matrix within a tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `tol` - Maximum allowed absolute deviation per matrix entry
     ///
     /// # Example
     ///
     /// ```
     /// use openmodel::primitives::Xform;
     /// let identity = Xform::identity();
-    /// assert!(identity.is_identity());
+    /// assert!(identity.is_identity(1e-6));
+    /// assert!(!Xform::translation(1.0, 0.0, 0.0).is_identity(1e-6));
     /// ```
-    pub fn is_identity(&self) -> bool {
-        let identity = Xform::identity();
-        for i in 0..16 {
-            if (self.m[i] - identity.m[i]).abs() > 1e-10 {
-                return false;
-            }
+    pub fn is_identity(&self, tol: f32) -> bool {
+        self.is_close(&Xform::IDENTITY, tol)
+    }
+
+    /// Checks if two transforms are equal within a tolerance, entry by entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The transform to compare with
+    /// * `tol` - Maximum allowed absolute deviation per matrix entry
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Xform;
+    /// let a = Xform::translation(1.0, 2.0, 3.0);
+    /// let b = Xform::translation(1.0, 2.0, 3.0 + 1e-7);
+    /// assert!(a.is_close(&b, 1e-6));
+    /// assert!(!a.is_close(&Xform::identity(), 1e-6));
+    /// ```
+    pub fn is_close(&self, other: &Xform, tol: f32) -> bool {
+        self.m
+            .iter()
+            .zip(other.m.iter())
+            .all(|(a, b)| (a - b).abs() <= tol)
+    }
+
+    /// Checks if this transform is rigid: the upper-left 3x3 block is orthonormal
+    /// with determinant +1 (rotation only) and the bottom row is (0, 0, 0, 1).
+    /// Translation is allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tol` - Tolerance for the orthonormality and determinant checks
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Xform;
+    /// let rigid = Xform::translation(1.0, 2.0, 3.0) * Xform::rotation_z(0.3);
+    /// assert!(rigid.is_rigid(1e-5));
+    /// assert!(!Xform::scaling(2.0, 1.0, 1.0).is_rigid(1e-5));
+    /// ```
+    pub fn is_rigid(&self, tol: f32) -> bool {
+        let m = &self.m;
+        if m[3].abs() > tol || m[7].abs() > tol || m[11].abs() > tol || (m[15] - 1.0).abs() > tol {
+            return false;
         }
-        true
+
+        // Columns of the 3x3 block must be unit length and mutually orthogonal
+        let col = |c: usize| Vector::new(m[c * 4], m[c * 4 + 1], m[c * 4 + 2]);
+        let (x, y, z) = (col(0), col(1), col(2));
+        let orthonormal = (x.dot(&x) - 1.0).abs() <= tol
+            && (y.dot(&y) - 1.0).abs() <= tol
+            && (z.dot(&z) - 1.0).abs() <= tol
+            && x.dot(&y).abs() <= tol
+            && y.dot(&z).abs() <= tol
+            && z.dot(&x).abs() <= tol;
+
+        // Reject reflections
+        orthonormal && (x.cross(&y).dot(&z) - 1.0).abs() <= tol
     }
 }
 
 // Implement Display for Xform
 impl fmt::Display for Xform {
+    /// Prints the 16 elements as four rows of four, in row-major reading order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openmodel::primitives::Xform;
+    /// let text = Xform::translation(1.0, 2.0, 3.0).to_string();
+    /// assert_eq!(text.lines().nth(1), Some("[1.0000, 0.0000, 0.0000, 1.0000]"));
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Transform Matrix:")?;
         writeln!(f, "[{:.4}, {:.4}, {:.4}, {:.4}]", self.m[0], self.m[4], self.m[8], self.m[12])?;
@@ -0,0 +1,37 @@
+use openmodel::common::{build_adjacency_graph, descendants_of, Data};
+
+#[test]
+fn test_assembly_graph_and_descendants() {
+    // assembly -> beam -> bolt, with a "contact" link between beam and bolt
+    let assembly = Data::with_name("assembly");
+    let mut beam = Data::with_name("beam");
+    let mut bolt = Data::with_name("bolt");
+    beam.set_parent(Some(assembly.guid()));
+    bolt.set_parent(Some(beam.guid()));
+    beam.add_adjacency(bolt.guid(), "contact");
+    bolt.add_adjacency(beam.guid(), "contact");
+    bolt.add_adjacency(assembly.guid(), "reference");
+
+    let objects = [&assembly, &beam, &bolt];
+    let graph = build_adjacency_graph(&objects);
+    assert_eq!(graph.len(), 3);
+    assert!(graph[&assembly.guid()].is_empty());
+    assert_eq!(graph[&beam.guid()], vec![(bolt.guid(), "contact".to_string())]);
+    assert_eq!(
+        graph[&bolt.guid()],
+        vec![(beam.guid(), "contact".to_string()), (assembly.guid(), "reference".to_string())]
+    );
+
+    assert_eq!(descendants_of(&objects, assembly.guid()), vec![beam.guid(), bolt.guid()]);
+    assert_eq!(descendants_of(&objects, beam.guid()), vec![bolt.guid()]);
+    assert!(descendants_of(&objects, bolt.guid()).is_empty());
+}
+
+#[test]
+fn test_descendants_stop_at_cycles() {
+    let mut a = Data::with_name("a");
+    let mut b = Data::with_name("b");
+    a.set_parent(Some(b.guid()));
+    b.set_parent(Some(a.guid()));
+    assert_eq!(descendants_of(&[&a, &b], a.guid()), vec![b.guid()]);
+}
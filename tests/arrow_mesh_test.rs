@@ -0,0 +1,86 @@
+use openmodel::geometry::{Arrow, Point};
+use openmodel::primitives::Xform;
+
+#[test]
+fn test_arrow_mesh_sides_and_head() {
+    let mut arrow = Arrow::new(0.0, 0.0, 0.0, 0.0, 0.0, 2.0);
+    arrow.data.set_thickness(0.1);
+    arrow.set_pipe_sides(16);
+    let mesh = arrow.get_mesh().expect("arrow mesh");
+
+    // Shaft: two rings of 16, head: base ring of 16 plus the apex
+    assert_eq!(mesh.number_of_vertices(), 3 * 16 + 1);
+    // Shaft quads, cone triangles and the cone base cap
+    assert_eq!(mesh.number_of_faces(), 16 + 16 + 1);
+
+    let apex = Point::new(0.0, 0.0, 2.0);
+    let has_apex = mesh.vertex.keys().any(|&k| {
+        mesh.vertex_position(k).map(|p| p.distance(&apex) < 1e-6).unwrap_or(false)
+    });
+    assert!(has_apex, "cone head should end at the arrow end point");
+}
+
+#[test]
+fn test_arrow_mesh_without_head() {
+    let mut arrow = Arrow::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    arrow.set_pipe_sides(6).set_head(false);
+    let mesh = arrow.get_mesh().expect("arrow mesh");
+    assert_eq!(mesh.number_of_vertices(), 12);
+    assert_eq!(mesh.number_of_faces(), 6);
+}
+
+#[test]
+fn test_arrow_pipe_sides_serialization() {
+    let mut arrow = Arrow::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    arrow.set_pipe_sides(12).set_head(false);
+    let text = serde_json::to_string(&arrow).unwrap();
+    let restored: Arrow = serde_json::from_str(&text).unwrap();
+    assert_eq!(restored.pipe_sides, 12);
+    assert!(!restored.head);
+
+    // Older files without the fields fall back to the defaults
+    let mut value = serde_json::to_value(&arrow).unwrap();
+    let object = value.as_object_mut().unwrap();
+    object.remove("pipe_sides");
+    object.remove("head");
+    let legacy: Arrow = serde_json::from_value(value).unwrap();
+    assert_eq!(legacy.pipe_sides, 8);
+    assert!(legacy.head);
+}
+
+fn assert_close(a: &Point, b: &Point) {
+    assert!(a.distance(b) < 1e-5, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn test_arrow_pipe_transform_diagonal() {
+    let arrow = Arrow::new(1.0, 2.0, 3.0, 3.0, 4.0, 5.0);
+    let xform = arrow.to_pipe_transform().expect("non-zero arrow");
+
+    // The unit pipe's end caps land on the arrow's end points
+    assert_close(&xform.transform_point(&Point::new(0.0, 0.0, -0.5)), &Point::new(1.0, 2.0, 3.0));
+    assert_close(&xform.transform_point(&Point::new(0.0, 0.0, 0.5)), &Point::new(3.0, 4.0, 5.0));
+    assert_close(&xform.transform_point(&Point::new(0.0, 0.0, 0.0)), &Point::new(2.0, 3.0, 4.0));
+
+    // The pipe radius is not scaled, only its length
+    let side = xform.transform_point(&Point::new(1.0, 0.0, 0.0));
+    assert!((side.distance(&Point::new(2.0, 3.0, 4.0)) - 1.0).abs() < 1e-5);
+    assert!((xform.determinant() - 12f32.sqrt()).abs() < 1e-4);
+}
+
+#[test]
+fn test_arrow_pipe_transform_downward_and_degenerate() {
+    let down = Arrow::new(0.0, 0.0, 2.0, 0.0, 0.0, 0.0);
+    let xform = down.to_pipe_transform().unwrap();
+    assert_close(&xform.transform_point(&Point::new(0.0, 0.0, 0.5)), &Point::new(0.0, 0.0, 0.0));
+
+    assert!(Arrow::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0).to_pipe_transform().is_none());
+}
+
+#[test]
+fn test_arrow_pipe_transform_uses_stored_matrix() {
+    let mut arrow = Arrow::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+    let stored = Xform::translation(5.0, 0.0, 0.0) * Xform::rotation_x(0.5);
+    arrow.data.set_transformation(stored.m);
+    assert!(arrow.to_pipe_transform().unwrap().is_close(&stored, 0.0));
+}
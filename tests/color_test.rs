@@ -0,0 +1,70 @@
+use openmodel::primitives::Color;
+
+#[test]
+fn test_invert_red_is_cyan() {
+    assert_eq!(Color::red().invert(), Color::cyan());
+    let c = Color::new(10, 20, 30, 40);
+    assert_eq!(c.invert(), Color::new(245, 235, 225, 40));
+    assert_eq!(c.invert().invert(), c);
+}
+
+#[test]
+fn test_with_alpha_changes_only_alpha() {
+    let c = Color::new(10, 20, 30, 255).with_alpha(7);
+    assert_eq!((c.r, c.g, c.b, c.a), (10, 20, 30, 7));
+}
+
+#[test]
+fn test_color_json_hex_round_trip() {
+    use openmodel::common::FromJsonData;
+
+    let color = Color::new(18, 52, 86, 120);
+    let value = color.to_json_hex();
+    assert_eq!(value, serde_json::json!("#12345678"));
+    assert_eq!(Color::from_json(&value), Some(color));
+
+    // Lowercase and alpha-less hex strings are accepted too
+    let parsed = Color::from_json_data(&serde_json::json!("#ff8000")).unwrap();
+    assert_eq!((parsed.r, parsed.g, parsed.b, parsed.a), (255, 128, 0, 255));
+
+    // The struct form still works, plain and wrapped
+    let plain = serde_json::to_value(color).unwrap();
+    assert_eq!(Color::from_json(&plain), Some(color));
+    let wrapped = serde_json::json!({"dtype": "openmodel.primitives/Color", "data": "#12345678"});
+    assert_eq!(Color::from_json(&wrapped), Some(color));
+
+    assert_eq!(Color::from_json(&serde_json::json!("#12")), None);
+    assert_eq!(Color::from_json(&serde_json::json!({"r": 1})), None);
+}
+
+#[test]
+fn test_palette_evenly_spaced_hues() {
+    assert_eq!(Color::palette(3), vec![Color::red(), Color::green(), Color::blue()]);
+    assert!(Color::palette(0).is_empty());
+
+    let colors = Color::palette(6);
+    assert_eq!(colors.len(), 6);
+    assert_eq!(colors[1], Color::yellow());
+    assert_eq!(colors[3], Color::cyan());
+    assert_eq!(colors[5], Color::magenta());
+    assert!(colors.iter().all(|c| c.a == 255));
+}
+
+#[test]
+fn test_from_hsv_wraps_hue_and_scales_value() {
+    assert_eq!(Color::from_hsv(360.0, 1.0, 1.0), Color::red());
+    assert_eq!(Color::from_hsv(-120.0, 1.0, 1.0), Color::blue());
+    assert_eq!(Color::from_hsv(0.0, 0.0, 0.5), Color::rgb(128, 128, 128));
+}
+
+#[test]
+fn test_from_json_rejects_malformed_input() {
+    // Six and eight bytes long, but not six or eight hex digits
+    assert_eq!(Color::from_json(&serde_json::json!("a€bc")), None);
+    assert_eq!(Color::from_json(&serde_json::json!("#12€456")), None);
+    assert_eq!(Color::from_hex("ab€cde"), None);
+
+    // Channels outside 0..=255 are rejected, not wrapped
+    let value = serde_json::json!({"r": 300, "g": 0, "b": 0, "a": 255});
+    assert_eq!(Color::from_json(&value), None);
+}
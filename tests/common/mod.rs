@@ -0,0 +1,43 @@
+//! Fixtures shared by the integration tests.
+
+use openmodel::geometry::{Mesh, Point};
+
+/// The six quads of the unit cube [0, 1]^3, oriented with outward normals.
+#[allow(dead_code)]
+pub fn unit_cube_polygons() -> Vec<Vec<Point>> {
+    vec![
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(1.0, 0.0, 0.0)],
+        vec![Point::new(0.0, 0.0, 1.0), Point::new(1.0, 0.0, 1.0), Point::new(1.0, 1.0, 1.0), Point::new(0.0, 1.0, 1.0)],
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(1.0, 0.0, 1.0), Point::new(0.0, 0.0, 1.0)],
+        vec![Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(1.0, 1.0, 1.0), Point::new(1.0, 0.0, 1.0)],
+        vec![Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0), Point::new(0.0, 1.0, 1.0), Point::new(1.0, 1.0, 1.0)],
+        vec![Point::new(0.0, 1.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 1.0), Point::new(0.0, 1.0, 1.0)],
+    ]
+}
+
+/// Two triangles sharing a diagonal of the square [0, 10]^2. The second triangle's
+/// copies of the shared corners are pushed `offset` away from the first's.
+#[allow(dead_code)]
+pub fn two_triangles(offset: f32) -> Vec<Vec<Point>> {
+    vec![
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0)],
+        vec![Point::new(10.0 + offset, 0.0, 0.0), Point::new(10.0, 10.0, 0.0), Point::new(0.0, 10.0 + offset, 0.0)],
+    ]
+}
+
+/// The closed unit cube [0, 1]^3 built from `unit_cube_polygons`.
+#[allow(dead_code)]
+pub fn unit_cube() -> Mesh {
+    Mesh::from_polygons(unit_cube_polygons(), None)
+}
+
+/// The unit right triangle (0, 0, 0), (1, 0, 0), (0, 1, 0), counter-clockwise in XY.
+#[allow(dead_code)]
+pub fn triangle() -> Mesh {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    mesh
+}
@@ -0,0 +1,33 @@
+use openmodel::geometry::{Plane, Point, Vector};
+use openmodel::primitives::Coords;
+
+fn centroid(items: &[&dyn Coords]) -> Option<[f64; 3]> {
+    if items.is_empty() {
+        return None;
+    }
+    let mut sum = [0.0; 3];
+    for item in items {
+        let c = item.coords();
+        sum[0] += c[0];
+        sum[1] += c[1];
+        sum[2] += c[2];
+    }
+    let n = items.len() as f64;
+    Some([sum[0] / n, sum[1] / n, sum[2] / n])
+}
+
+#[test]
+fn test_centroid_of_mixed_coords() {
+    let a = Point::new(0.0, 0.0, 0.0);
+    let b = Point::new(4.0, 0.0, 0.0);
+    let plane = Plane::from_point_normal(&Point::new(2.0, 6.0, 3.0), &Vector::new(0.0, 0.0, 1.0));
+    let items: Vec<&dyn Coords> = vec![&a, &b, &plane];
+    assert_eq!(centroid(&items), Some([2.0, 2.0, 1.0]));
+}
+
+#[test]
+fn test_coords_widen_to_f64() {
+    let v = Vector::new(0.1, -2.5, 3.0);
+    assert_eq!(v.coords(), [0.1f32 as f64, -2.5, 3.0]);
+    assert_eq!(Point::new(1.0, 2.0, 3.0).coords(), [1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,36 @@
+use openmodel::common::{json_dump, json_load};
+use openmodel::geometry::Vector;
+use openmodel::Error;
+
+#[test]
+fn test_json_load_missing_file_is_io_error() {
+    let path = std::env::temp_dir().join("openmodel_error_test_missing.json");
+    let _ = std::fs::remove_file(&path);
+    let result: Result<Vector, Error> = json_load(path.to_str().unwrap());
+    assert!(matches!(result, Err(Error::Io(_))));
+}
+
+#[test]
+fn test_json_load_malformed_is_parse_error() {
+    let path = std::env::temp_dir().join("openmodel_error_test_malformed.json");
+    std::fs::write(&path, "{ \"x\": 1.0, ").unwrap();
+    let result: Result<Vector, Error> = json_load(path.to_str().unwrap());
+    let _ = std::fs::remove_file(&path);
+    assert!(matches!(result, Err(Error::Parse(_))));
+}
+
+#[test]
+fn test_json_dump_load_roundtrip() {
+    let path = std::env::temp_dir().join("openmodel_error_test_roundtrip.json");
+    json_dump(&Vector::new(1.0, 2.0, 3.0), path.to_str().unwrap()).unwrap();
+    let p: Vector = json_load(path.to_str().unwrap()).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(p, Vector::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_error_display() {
+    let e = Error::IndexOutOfBounds { index: 5, len: 3 };
+    assert_eq!(e.to_string(), "index 5 out of bounds for length 3");
+    assert_eq!(Error::Singular.to_string(), "singular matrix");
+}
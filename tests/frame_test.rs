@@ -0,0 +1,44 @@
+use openmodel::geometry::{Frame, Plane, Point, Vector};
+use openmodel::primitives::{Transformable, Xform};
+
+fn close(a: &Vector, b: &Vector) -> bool {
+    (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6 && (a.z - b.z).abs() < 1e-6
+}
+
+#[test]
+fn test_frame_plane_round_trip() {
+    let frame = Frame::new(Point::new(1.0, -2.0, 0.5), Vector::new(1.0, 1.0, 0.0), Vector::new(-1.0, 1.0, 1.0));
+    let plane = frame.to_plane();
+    assert_eq!(plane.origin, frame.origin);
+    assert!(close(&plane.zaxis, &frame.zaxis));
+
+    let back = Frame::from_plane(&plane);
+    assert_eq!(back.origin, frame.origin);
+    assert!(close(&back.xaxis, &frame.xaxis));
+    assert!(close(&back.yaxis, &frame.yaxis));
+    assert!(close(&back.zaxis, &frame.zaxis));
+}
+
+#[test]
+fn test_frame_axes_are_orthonormal() {
+    let frame = Frame::new(Point::new(0.0, 0.0, 0.0), Vector::new(3.0, 0.0, 0.0), Vector::new(1.0, 2.0, 0.0));
+    for axis in [&frame.xaxis, &frame.yaxis, &frame.zaxis] {
+        assert!((axis.length() - 1.0).abs() < 1e-6);
+    }
+    assert!(frame.xaxis.dot(&frame.yaxis).abs() < 1e-6);
+    assert!(close(&frame.xaxis.cross(&frame.yaxis), &frame.zaxis));
+}
+
+#[test]
+fn test_frame_transform_stays_rigid() {
+    let frame = Frame::default();
+    let xform = Xform::translation(1.0, 2.0, 3.0) * Xform::rotation_z(std::f32::consts::FRAC_PI_2) * Xform::scaling(2.0, 3.0, 4.0);
+    let moved = frame.transformed(&xform);
+    assert_eq!(moved.origin, Point::new(1.0, 2.0, 3.0));
+    assert!(close(&moved.xaxis, &Vector::new(0.0, 1.0, 0.0)));
+    assert!(close(&moved.yaxis, &Vector::new(-1.0, 0.0, 0.0)));
+    assert!(close(&moved.zaxis, &Vector::new(0.0, 0.0, 1.0)));
+
+    let plane: Plane = moved.to_plane();
+    assert!((plane.d + 3.0).abs() < 1e-6);
+}
@@ -0,0 +1,83 @@
+use openmodel::geometry::{Line, Vector};
+
+#[test]
+fn test_offset_x_axis_line_with_z_normal() {
+    let mut line = Line::new(0.0, 0.0, 0.0, 5.0, 0.0, 0.0);
+    line.data.set_color([0, 128, 255]);
+    let offset = line.offset(1.0, &Vector::new(0.0, 0.0, 1.0));
+
+    assert_eq!((offset.x0, offset.y0, offset.z0), (0.0, 1.0, 0.0));
+    assert_eq!((offset.x1, offset.y1, offset.z1), (5.0, 1.0, 0.0));
+    assert_eq!(offset.data.get_color(), [0, 128, 255]);
+    assert_ne!(offset.data.guid(), line.data.guid());
+
+    let back = line.offset(-2.0, &Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(back.y0, -2.0);
+}
+
+#[test]
+fn test_offset_degenerate_line_is_unchanged() {
+    let line = Line::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+    let offset = line.offset(3.0, &Vector::new(0.0, 0.0, 1.0));
+    assert_eq!((offset.x0, offset.y0, offset.z0), (1.0, 1.0, 1.0));
+    assert_eq!((offset.x1, offset.y1, offset.z1), (1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_line_canonical_equality_and_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    let line = Line::new(1.0, 2.0, 3.0, -1.0, 0.0, 5.0);
+    let reversed = Line::new(-1.0, 0.0, 5.0, 1.0, 2.0, 3.0);
+    assert_ne!(line, reversed);
+    assert_eq!(line.canonical(), reversed.canonical());
+
+    let hash = |l: &Line| {
+        let mut hasher = DefaultHasher::new();
+        l.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&line), hash(&reversed));
+
+    // -0.0 and 0.0 compare equal and must hash the same
+    let zero = Line::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+    let negative_zero = Line::new(-0.0, 0.0, -0.0, 1.0, 1.0, 1.0);
+    assert_eq!(zero, negative_zero);
+    assert_eq!(hash(&zero), hash(&negative_zero));
+
+    let set: HashSet<Line> = [line.canonical(), reversed.canonical(), zero].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_arrow_equality_ignores_data() {
+    use openmodel::geometry::Arrow;
+    let mut a = Arrow::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    let b = Arrow::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    a.data.set_thickness(2.0);
+    assert_eq!(a, b);
+    assert_ne!(a, Arrow::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_line_pipe_transform_maps_unit_pipe_onto_line() {
+    use openmodel::geometry::Point;
+    let lines = [
+        Line::new(0.0, 0.0, 0.0, 0.0, 0.0, 2.0),
+        Line::new(1.0, 2.0, 3.0, 1.0, 2.0, -1.0),
+        Line::new(-1.0, 0.5, 2.0, 3.0, -2.0, 0.0),
+        Line::new(0.0, 0.0, 0.0, 5.0, 0.0, 0.0),
+    ];
+    for line in &lines {
+        let xform = line.to_pipe_transform().expect("non-degenerate line");
+        let start = xform.transform_point(&Point::new(0.0, 0.0, -0.5));
+        let end = xform.transform_point(&Point::new(0.0, 0.0, 0.5));
+        assert!(start.distance(&Point::new(line.x0, line.y0, line.z0)) < 1e-5, "start of {}", line);
+        assert!(end.distance(&Point::new(line.x1, line.y1, line.z1)) < 1e-5, "end of {}", line);
+    }
+
+    // Zero-length lines have no pipe
+    assert!(Line::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0).to_pipe_transform().is_none());
+}
@@ -0,0 +1,57 @@
+use openmodel::geometry::{Color, Line, LineCloud, Point};
+
+#[test]
+fn test_weighted_center_biased_to_longer_line() {
+    let short = Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    let long = Line::new(0.0, 4.0, 0.0, 4.0, 4.0, 0.0);
+    let lc = LineCloud::new(vec![short, long], vec![Color::red(), Color::blue()]);
+
+    let plain = lc.barycenter().unwrap();
+    let weighted = lc.weighted_center(|l| l.length()).unwrap();
+    let long_mid = Point::new(2.0, 4.0, 0.0);
+
+    assert_eq!(plain, Point::new(1.25, 2.0, 0.0));
+    assert!(weighted.distance(&long_mid) < plain.distance(&long_mid));
+    // (0.5 * 1 + 2 * 4) / 5, (0 * 1 + 4 * 4) / 5
+    assert!((weighted.x - 1.7).abs() < 1e-6);
+    assert!((weighted.y - 3.2).abs() < 1e-6);
+}
+
+#[test]
+fn test_weighted_center_empty_or_zero_weight() {
+    let empty = LineCloud::new(vec![], vec![]);
+    assert!(empty.weighted_center(|l| l.length()).is_none());
+    assert!(empty.barycenter().is_none());
+
+    let lc = LineCloud::new(vec![Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0)], vec![Color::red()]);
+    assert!(lc.weighted_center(|_| 0.0).is_none());
+}
+
+#[test]
+fn test_combined_mesh_and_pipe_transforms() {
+    use openmodel::geometry::Mesh;
+
+    let lines = vec![
+        Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+        Line::new(0.0, 1.0, 0.0, 0.0, 1.0, 2.0),
+        Line::new(1.0, 1.0, 1.0, 3.0, 2.0, 1.0),
+    ];
+    let colors = vec![Color::red(), Color::green(), Color::blue()];
+    let lc = LineCloud::new(lines.clone(), colors.clone());
+
+    assert_eq!(lc.to_pipe_transforms().len(), lc.lines.len());
+
+    let pipes: Vec<Mesh> = lines
+        .iter()
+        .map(|l| Mesh::create_pipe(Point::new(l.x0, l.y0, l.z0), Point::new(l.x1, l.y1, l.z1), lc.data.get_thickness()))
+        .collect();
+    let combined = lc.to_combined_mesh();
+    assert_eq!(combined.number_of_faces(), pipes.iter().map(|m| m.number_of_faces()).sum::<usize>());
+    assert_eq!(combined.number_of_vertices(), pipes.iter().map(|m| m.number_of_vertices()).sum::<usize>());
+
+    // Each pipe's vertices carry their line color
+    for color in &colors {
+        let count = combined.vertex.keys().filter(|vk| combined.vertex_color(**vk) == Some(*color)).count();
+        assert_eq!(count, pipes[0].number_of_vertices());
+    }
+}
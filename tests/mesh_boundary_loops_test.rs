@@ -0,0 +1,110 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+/// 3x3 grid of unit quads with the center quad left out.
+fn grid_with_hole() -> Mesh {
+    let mut polygons = Vec::new();
+    for j in 0..3 {
+        for i in 0..3 {
+            if (i, j) == (1, 1) {
+                continue;
+            }
+            let (x, y) = (i as f32, j as f32);
+            polygons.push(vec![
+                Point::new(x, y, 0.0),
+                Point::new(x + 1.0, y, 0.0),
+                Point::new(x + 1.0, y + 1.0, 0.0),
+                Point::new(x, y + 1.0, 0.0),
+            ]);
+        }
+    }
+    Mesh::from_polygons(polygons, None)
+}
+
+#[test]
+fn test_grid_with_hole_has_outer_and_inner_loops() {
+    let mesh = grid_with_hole();
+    let loops = mesh.boundary_loops();
+    let point_loops = mesh.boundary_loops_points();
+
+    assert_eq!(loops.len(), 2);
+    assert_eq!(point_loops.len(), 2);
+    let mut lengths: Vec<usize> = loops.iter().map(|l| l.len()).collect();
+    lengths.sort_unstable();
+    assert_eq!(lengths, vec![4, 12]);
+
+    for (keys, points) in loops.iter().zip(&point_loops) {
+        assert_eq!(keys.len(), points.len());
+        for (k, p) in keys.iter().zip(points) {
+            assert_eq!(mesh.vertex_position(*k).unwrap(), *p);
+        }
+        // Consecutive loop vertices share a boundary edge
+        for i in 0..keys.len() {
+            assert!(mesh.is_edge_on_boundary(keys[i], keys[(i + 1) % keys.len()]));
+        }
+    }
+
+    let inner = point_loops.iter().find(|l| l.len() == 4).unwrap();
+    assert!(inner.iter().all(|p| (1.0..=2.0).contains(&p.x) && (1.0..=2.0).contains(&p.y)));
+}
+
+#[test]
+fn test_triangle_has_one_loop() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    let loops = mesh.boundary_loops();
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0].len(), 3);
+}
+
+#[test]
+fn test_cube_has_no_loops() {
+    assert!(unit_cube().boundary_loops().is_empty());
+}
+
+#[test]
+fn test_grid_with_corner_face_removed_has_one_loop() {
+    let mut grid = Mesh::create_grid(3.0, 3.0, 3, 3);
+    let corner = grid.grid_vertex_key(0, 0).unwrap();
+    let face = grid.vertex_faces(corner)[0];
+    assert!(grid.remove_face(face));
+
+    let loops = grid.boundary_loops();
+    assert_eq!(loops.len(), 1);
+    // 12 outer vertices minus the cut corner plus the inner corner of the notch
+    assert_eq!(loops[0].len(), 12);
+    assert!(!loops[0].contains(&corner));
+}
+
+/// Key of the grid quad whose lowest corner is grid vertex (i, j).
+fn grid_face(grid: &Mesh, i: usize, j: usize) -> usize {
+    let lowest = grid.vertex_position(grid.grid_vertex_key(i, j).unwrap()).unwrap();
+    *grid
+        .face
+        .iter()
+        .find(|(_, fverts)| {
+            fverts.iter().all(|v| {
+                let p = grid.vertex_position(*v).unwrap();
+                p.x >= lowest.x && p.y >= lowest.y && p.x <= lowest.x + 1.0 && p.y <= lowest.y + 1.0
+            })
+        })
+        .unwrap()
+        .0
+}
+
+#[test]
+fn test_disjoint_holes() {
+    let mut grid = Mesh::create_grid(5.0, 5.0, 5, 5);
+    let (fa, fb) = (grid_face(&grid, 1, 1), grid_face(&grid, 3, 3));
+    grid.remove_face(fa);
+    grid.remove_face(fb);
+
+    let mut lengths: Vec<usize> = grid.boundary_loops().iter().map(|l| l.len()).collect();
+    lengths.sort_unstable();
+    assert_eq!(lengths, vec![4, 4, 20]);
+}
@@ -0,0 +1,55 @@
+use openmodel::geometry::{Mesh, Point};
+
+fn build(mut mesh: Mesh, nx: usize, ny: usize) -> Mesh {
+    let mut keys = Vec::new();
+    for j in 0..=ny {
+        for i in 0..=nx {
+            keys.push(mesh.add_vertex(Point::new(i as f32, j as f32, 0.0), None));
+        }
+    }
+    for j in 0..ny {
+        for i in 0..nx {
+            let a = keys[j * (nx + 1) + i];
+            let b = keys[j * (nx + 1) + i + 1];
+            let c = keys[(j + 1) * (nx + 1) + i + 1];
+            let d = keys[(j + 1) * (nx + 1) + i];
+            mesh.add_face(vec![a, b, c], None);
+            mesh.add_face(vec![a, c, d], None);
+        }
+    }
+    mesh
+}
+
+fn assert_same_mesh(a: &Mesh, b: &Mesh) {
+    assert_eq!(a.face, b.face);
+    assert_eq!(a.halfedge, b.halfedge);
+    assert_eq!(a.vertex.len(), b.vertex.len());
+    for vk in a.vertex.keys() {
+        assert_eq!(a.vertex_position(*vk), b.vertex_position(*vk));
+    }
+}
+
+#[test]
+fn test_with_capacity_builds_identical_mesh() {
+    let plain = build(Mesh::new(), 20, 10);
+    let hinted = build(Mesh::with_capacity(21 * 11, 400), 20, 10);
+    assert!(hinted.vertex.capacity() >= 21 * 11);
+    assert_same_mesh(&plain, &hinted);
+}
+
+#[test]
+fn test_from_polygons_with_capacity_estimate() {
+    let grid = build(Mesh::new(), 8, 8);
+    let polygons: Vec<Vec<Point>> = {
+        let mut fkeys: Vec<usize> = grid.face.keys().copied().collect();
+        fkeys.sort_unstable();
+        fkeys
+            .iter()
+            .map(|fk| grid.face[fk].iter().map(|vk| grid.vertex_position(*vk).unwrap()).collect())
+            .collect()
+    };
+    let mesh = Mesh::from_polygons(polygons, None);
+    assert_eq!(mesh.number_of_vertices(), 81);
+    assert_eq!(mesh.number_of_faces(), 128);
+    assert_eq!(mesh.euler(), 1);
+}
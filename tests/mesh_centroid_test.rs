@@ -0,0 +1,58 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+fn assert_close(p: Point, x: f32, y: f32, z: f32) {
+    assert!(p.distance(&Point::new(x, y, z)) < 1e-6, "{} != ({}, {}, {})", p, x, y, z);
+}
+
+#[test]
+fn test_cube_centroid_and_center_of_mass() {
+    let cube = unit_cube();
+    assert_close(cube.centroid(), 0.5, 0.5, 0.5);
+    assert_close(cube.center_of_mass().unwrap(), 0.5, 0.5, 0.5);
+}
+
+#[test]
+fn test_center_of_mass_ignores_vertex_density() {
+    // A large triangle and a tiny one: the vertex average is pulled towards the
+    // tiny triangle, the area-weighted center is not
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(3.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 3.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    let d = mesh.add_vertex(Point::new(10.0, 0.0, 0.0), None);
+    let e = mesh.add_vertex(Point::new(10.001, 0.0, 0.0), None);
+    let f = mesh.add_vertex(Point::new(10.0, 0.001, 0.0), None);
+    mesh.add_face(vec![d, e, f], None);
+
+    assert!(mesh.centroid().x > 5.0);
+    assert!((mesh.center_of_mass().unwrap().x - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_empty_mesh() {
+    let mesh = Mesh::new();
+    assert_eq!(mesh.centroid(), Point::origin());
+    assert!(mesh.center_of_mass().is_none());
+}
+
+#[test]
+fn test_cube_bounding_box() {
+    let (min, max) = unit_cube().bounding_box().unwrap();
+    assert_eq!(min, Point::new(0.0, 0.0, 0.0));
+    assert_eq!(max, Point::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_bounding_box_single_vertex_and_empty() {
+    assert!(Mesh::new().bounding_box().is_none());
+
+    let mut mesh = Mesh::new();
+    mesh.add_vertex(Point::new(2.0, -3.0, 4.0), None);
+    let (min, max) = mesh.bounding_box().unwrap();
+    assert_eq!(min, max);
+    assert_eq!(min, Point::new(2.0, -3.0, 4.0));
+}
@@ -0,0 +1,31 @@
+use openmodel::geometry::{Mesh, Point};
+
+fn quad_strip() -> Mesh {
+    let polygons = vec![
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0)],
+        vec![Point::new(1.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(2.0, 1.0, 0.0), Point::new(1.0, 1.0, 0.0)],
+    ];
+    Mesh::from_polygons(polygons, None)
+}
+
+#[test]
+fn test_remove_isolated_vertices_restores_count() {
+    let mut mesh = quad_strip();
+    let original = mesh.number_of_vertices();
+    assert!(mesh.isolated_vertices().is_empty());
+
+    let stray = mesh.add_vertex(Point::new(5.0, 5.0, 5.0), None);
+    assert_eq!(mesh.isolated_vertices(), vec![stray]);
+
+    assert_eq!(mesh.remove_isolated_vertices(), 1);
+    assert_eq!(mesh.number_of_vertices(), original);
+    assert!(!mesh.halfedge.contains_key(&stray));
+    assert_eq!(mesh.remove_isolated_vertices(), 0);
+}
+
+#[test]
+fn test_naked_vertices_of_open_strip() {
+    let mesh = quad_strip();
+    // Every vertex of a single-row strip lies on the boundary
+    assert_eq!(mesh.naked_vertices().len(), 6);
+}
@@ -0,0 +1,60 @@
+use openmodel::geometry::{Color, Mesh, Point};
+use std::collections::HashMap;
+
+#[test]
+fn test_color_by_scalar_maps_min_and_max() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, d, c], None);
+
+    let values = HashMap::from([(a, -2.0), (b, 1.0), (c, 4.0)]);
+    let low = Color::blue();
+    let high = Color::red();
+    mesh.color_by_scalar(&values, &low, &high);
+
+    assert_eq!(mesh.vertex_color(a), Some(low));
+    assert_eq!(mesh.vertex_color(c), Some(high));
+    assert_eq!(mesh.vertex_color(b), Some(low.lerp(&high, 0.5)));
+    // Vertex without a value falls back to the low color
+    assert_eq!(mesh.vertex_color(d), Some(low));
+}
+
+#[test]
+fn test_color_lerp_endpoints() {
+    let a = Color::new(10, 20, 30, 40);
+    let b = Color::new(110, 120, 130, 140);
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 2.0), b);
+}
+
+#[test]
+fn test_face_colors_to_vertex_colors_averages_incident_faces() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let e = mesh.add_vertex(Point::new(-1.0, 0.5, 0.0), None);
+    let red = mesh.add_face(vec![a, b, c], None).unwrap();
+    let blue = mesh.add_face(vec![a, c, d], None).unwrap();
+    let uncolored = mesh.add_face(vec![a, d, e], None).unwrap();
+    assert!(mesh.set_face_color(red, &Color::red()));
+    assert!(mesh.set_face_color(blue, &Color::blue()));
+    assert_eq!(mesh.face_color(uncolored), None);
+
+    let original_e = mesh.vertex_color(e);
+    mesh.face_colors_to_vertex_colors();
+
+    // Shared by red and blue faces (the uncolored face is skipped): purple
+    let purple = mesh.vertex_color(a).unwrap();
+    assert!((purple.r as i32 - 128).abs() <= 1 && purple.g == 0 && (purple.b as i32 - 128).abs() <= 1);
+    assert_eq!(mesh.vertex_color(c), Some(purple));
+    assert_eq!(mesh.vertex_color(b), Some(Color::red()));
+    assert_eq!(mesh.vertex_color(d), Some(Color::blue()));
+    // Only touches an uncolored face
+    assert_eq!(mesh.vertex_color(e), original_e);
+}
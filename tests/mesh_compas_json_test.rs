@@ -0,0 +1,63 @@
+use openmodel::geometry::Mesh;
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_compas_json_layout() {
+    let mesh = unit_cube();
+    let json = mesh.to_compas_json();
+
+    assert_eq!(json["dtype"], "compas.datastructures/Mesh");
+    let vertex = json["data"]["vertex"].as_object().unwrap();
+    let face = json["data"]["face"].as_object().unwrap();
+    assert_eq!(vertex.len(), 8);
+    assert_eq!(face.len(), 6);
+    for (key, v) in vertex {
+        assert!(key.parse::<usize>().is_ok());
+        assert!(v["x"].is_number() && v["y"].is_number() && v["z"].is_number());
+    }
+}
+
+#[test]
+fn test_compas_json_round_trip() {
+    let mut mesh = unit_cube();
+    let fk = *mesh.face.keys().min().unwrap();
+    mesh.facedata.entry(fk).or_default().insert("weight".to_string(), 2.0);
+    let json = mesh.to_compas_json();
+
+    let restored = Mesh::from_compas_json(&json).expect("valid compas json");
+    assert_eq!(restored.number_of_vertices(), mesh.number_of_vertices());
+    assert_eq!(restored.number_of_faces(), mesh.number_of_faces());
+    for (key, v) in &mesh.vertex {
+        let r = &restored.vertex[key];
+        assert_eq!((r.x, r.y, r.z), (v.x, v.y, v.z));
+    }
+    for (key, f) in &mesh.face {
+        assert_eq!(&restored.face[key], f);
+    }
+    assert_eq!(restored.facedata[&fk]["weight"], 2.0);
+    assert_eq!(restored.edges().len(), mesh.edges().len());
+
+    // The bare data object is accepted as well
+    let bare = Mesh::from_compas_json(&json["data"]).unwrap();
+    assert_eq!(bare.number_of_faces(), 6);
+}
+
+#[test]
+fn test_compas_json_invalid_is_none() {
+    assert!(Mesh::from_compas_json(&serde_json::json!({"data": {"face": {}}})).is_none());
+}
+
+#[test]
+fn test_compas_json_rejected_face_is_none() {
+    let json = serde_json::json!({"data": {
+        "vertex": {
+            "0": {"x": 0.0, "y": 0.0, "z": 0.0},
+            "1": {"x": 1.0, "y": 0.0, "z": 0.0},
+            "2": {"x": 0.0, "y": 1.0, "z": 0.0}
+        },
+        "face": {"0": [0, 1, 2], "1": [0, 2, 7]}
+    }});
+    assert!(Mesh::from_compas_json(&json).is_none());
+}
@@ -0,0 +1,63 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+fn tetrahedron(offset: f32) -> Vec<Vec<Point>> {
+    let o = Point::new(offset, 0.0, 0.0);
+    let x = Point::new(offset + 1.0, 0.0, 0.0);
+    let y = Point::new(offset, 1.0, 0.0);
+    let z = Point::new(offset, 0.0, 1.0);
+    vec![vec![o, y, x], vec![o, x, z], vec![o, z, y], vec![x, y, z]]
+}
+
+#[test]
+fn test_cube_is_one_component() {
+    let cube = unit_cube();
+    let components = cube.connected_components();
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].len(), 6);
+
+    let parts = cube.split_components();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].number_of_vertices(), 8);
+    assert_eq!(parts[0].number_of_faces(), 6);
+    assert!((parts[0].volume() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_two_tetrahedra() {
+    let mut polygons = tetrahedron(0.0);
+    polygons.extend(tetrahedron(5.0));
+    let mesh = Mesh::from_polygons(polygons, None);
+    let components = mesh.connected_components();
+    assert_eq!(components.iter().map(|c| c.len()).collect::<Vec<_>>(), vec![4, 4]);
+
+    let parts = mesh.split_components();
+    assert_eq!(parts.len(), 2);
+    for part in &parts {
+        assert_eq!(part.number_of_vertices(), 4);
+        assert_eq!(part.euler(), 2);
+    }
+    assert!(parts[1].bounding_box().unwrap().0.x >= 5.0);
+}
+
+#[test]
+fn test_two_disjoint_triangles() {
+    let polygons = vec![
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)],
+        vec![Point::new(3.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0), Point::new(3.0, 1.0, 0.0)],
+    ];
+    let mesh = Mesh::from_polygons(polygons, None);
+    assert_eq!(mesh.connected_components().len(), 2);
+    let parts = mesh.split_components();
+    assert!(parts.iter().all(|m| m.number_of_vertices() == 3 && m.number_of_faces() == 1));
+}
+
+#[test]
+fn test_vertices_and_faces_roundtrip() {
+    let cube = unit_cube();
+    let (vertices, faces) = cube.to_vertices_and_faces();
+    let copy = Mesh::from_vertices_and_faces(&vertices, &faces);
+    assert_eq!(copy.to_vertices_and_faces(), (vertices, faces));
+}
@@ -0,0 +1,49 @@
+use openmodel::geometry::{Mesh, Point};
+
+#[test]
+fn test_cotangent_weights_two_right_triangles() {
+    // 2 x 1 rectangle split along its diagonal a-c
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(2.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(2.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    mesh.add_face(vec![a, c, d], None);
+
+    let w = mesh.cotangent_weights();
+    assert_eq!(w.len(), 5);
+    // Shared hypotenuse: both opposite angles are right angles
+    assert!(w[&(a, c)].abs() < 1e-6);
+    // Long sides are opposite the angle at c (in abc) or a (in acd): cot = 1 / 2
+    assert!((w[&(a, b)] - 0.5 * 0.5).abs() < 1e-5);
+    assert!((w[&(c, d)] - 0.5 * 0.5).abs() < 1e-5);
+    // Short sides are opposite the angle at a (in abc) or c (in acd): cot = 2 / 1
+    assert!((w[&(b, c)] - 0.5 * 2.0).abs() < 1e-5);
+    assert!((w[&(a, d)] - 0.5 * 2.0).abs() < 1e-5);
+
+    let dual = mesh.dual_edge_lengths();
+    assert!((dual[&(a, b)] - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn test_cotangent_weights_interior_edge_sums_both_sides() {
+    // Equilateral diamond: the interior edge sees two 60 degree angles
+    let h = 3.0_f32.sqrt() / 2.0;
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.5, h, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.5, -h, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    mesh.add_face(vec![b, a, d], None);
+
+    let cot60 = 1.0 / 3.0_f32.sqrt();
+    assert!((mesh.cotangent_weights()[&(a, b)] - cot60).abs() < 1e-5);
+}
+
+#[test]
+fn test_cotangent_weights_require_triangles() {
+    let mesh = Mesh::create_grid(1.0, 1.0, 2, 2);
+    assert!(mesh.cotangent_weights().is_empty());
+}
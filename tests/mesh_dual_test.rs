@@ -0,0 +1,26 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_dual_of_cube_is_octahedron() {
+    let dual = unit_cube().dual();
+    assert_eq!(dual.number_of_vertices(), 6);
+    assert_eq!(dual.number_of_faces(), 8);
+    assert!(dual.face.values().all(|fverts| fverts.len() == 3));
+    assert_eq!(dual.number_of_edges(), 12);
+    assert!(dual.naked_vertices().is_empty());
+    // Dual vertices sit at the face centers and the winding follows the cube's
+    assert!(dual.vertex.values().all(|v| v.position().distance(&Point::new(0.5, 0.5, 0.5)) - 0.5 < 1e-6));
+    assert!((dual.volume() - 1.0 / 6.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_dual_of_grid_skips_boundary_vertices() {
+    let grid = Mesh::create_grid(4.0, 4.0, 4, 4);
+    let dual = grid.dual();
+    assert_eq!(dual.number_of_vertices(), 16);
+    assert_eq!(dual.number_of_faces(), 9);
+    assert!(dual.face.values().all(|fverts| fverts.len() == 4));
+}
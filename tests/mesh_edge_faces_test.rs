@@ -0,0 +1,36 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_edge_faces_closed_cube() {
+    let mesh = unit_cube();
+    for (u, v) in mesh.edges() {
+        let faces = mesh.edge_faces(u, v);
+        assert_eq!(faces.len(), 2, "edge ({}, {})", u, v);
+        assert_ne!(faces[0], faces[1]);
+        for fk in faces {
+            let fverts = mesh.face_vertices(fk).unwrap();
+            assert!(fverts.contains(&u) && fverts.contains(&v));
+        }
+    }
+}
+
+#[test]
+fn test_edge_faces_boundary_and_missing_edges() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let f1 = mesh.add_face(vec![a, b, c], None).unwrap();
+    let f2 = mesh.add_face(vec![a, c, d], None).unwrap();
+
+    // Halfedge (a, c) belongs to f2, (c, a) to f1
+    assert_eq!(mesh.edge_faces(a, c), vec![f2, f1]);
+    assert_eq!(mesh.edge_faces(c, a), vec![f1, f2]);
+    assert_eq!(mesh.edge_faces(a, b), vec![f1]);
+    assert_eq!(mesh.edge_faces(d, a), vec![f2]);
+    assert!(mesh.edge_faces(b, d).is_empty());
+}
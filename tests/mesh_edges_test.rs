@@ -0,0 +1,31 @@
+use openmodel::geometry::{Mesh, Point};
+
+#[test]
+fn test_unit_triangle_edges_and_lengths() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+
+    let edges = mesh.edges();
+    assert_eq!(edges.len(), 3);
+    assert!(edges.iter().all(|(u, v)| u < v));
+
+    let mut lengths: Vec<f32> = edges.iter().map(|(u, v)| mesh.edge_length(*u, *v).unwrap()).collect();
+    lengths.sort_by(f32::total_cmp);
+    assert_eq!(lengths[0], 1.0);
+    assert_eq!(lengths[1], 1.0);
+    assert!((lengths[2] - 2f32.sqrt()).abs() < 1e-6);
+
+    assert_eq!(mesh.edge_length(b, c), mesh.edge_length(c, b));
+}
+
+#[test]
+fn test_edge_length_of_non_edge() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    assert!(mesh.edge_length(a, b).is_none());
+    assert!(mesh.edge_length(a, 42).is_none());
+}
@@ -0,0 +1,39 @@
+use openmodel::geometry::{Mesh, Point};
+
+#[test]
+fn test_equilateral_triangle_angles() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.5, 3.0_f32.sqrt() / 2.0, 0.0), None);
+    let f = mesh.add_face(vec![a, b, c], None).unwrap();
+
+    let angles = mesh.face_angles(f);
+    assert_eq!(angles.len(), 3);
+    for angle in angles {
+        assert!((angle.to_degrees() - 60.0).abs() < 1e-3);
+    }
+    assert!((mesh.face_min_angle(f).unwrap().to_degrees() - 60.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_sliver_and_quad_angles() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(10.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(5.0, 0.01, 0.0), None);
+    let d = mesh.add_vertex(Point::new(10.0, 10.0, 0.0), None);
+    let e = mesh.add_vertex(Point::new(0.0, 10.0, 0.0), None);
+    let sliver = mesh.add_face(vec![a, b, c], None).unwrap();
+    let quad = mesh.add_face(vec![a, b, d, e], None).unwrap();
+
+    assert!(mesh.face_min_angle(sliver).unwrap().to_degrees() < 0.5);
+    let sum: f32 = mesh.face_angles(sliver).iter().sum();
+    assert!((sum - std::f32::consts::PI).abs() < 1e-4);
+    for angle in mesh.face_angles(quad) {
+        assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    }
+
+    assert!(mesh.face_angles(99).is_empty());
+    assert!(mesh.face_min_angle(99).is_none());
+}
@@ -0,0 +1,28 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_feature_edges_cube() {
+    let cube = unit_cube();
+    let edges = cube.feature_edges(45f32.to_radians());
+    assert_eq!(edges.len(), 12);
+}
+
+#[test]
+fn test_feature_edges_coplanar_triangles_only_boundary() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    mesh.add_face(vec![a, c, d], None);
+
+    let edges = mesh.feature_edges(45f32.to_radians());
+    assert_eq!(edges.len(), 4);
+    let diagonal = if a < c { (a, c) } else { (c, a) };
+    assert!(!edges.contains(&diagonal));
+    assert_eq!(mesh.dihedral_angle(a, c), Some(0.0));
+}
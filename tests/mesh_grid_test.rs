@@ -0,0 +1,37 @@
+use openmodel::geometry::Mesh;
+
+#[test]
+fn test_grid_vertex_key_corners() {
+    let (nx, ny) = (4, 3);
+    let grid = Mesh::create_grid(4.0, 3.0, nx, ny);
+
+    let origin = grid.grid_vertex_key(0, 0).expect("corner (0,0)");
+    let far = grid.grid_vertex_key(nx, ny).expect("corner (nx,ny)");
+    assert_ne!(origin, far);
+
+    let p = grid.vertex_position(far).unwrap();
+    assert_eq!((p.x, p.y, p.z), (4.0, 3.0, 0.0));
+    assert!(grid.grid_vertex_key(nx + 1, 0).is_none());
+}
+
+#[test]
+fn test_grid_counts() {
+    let grid = Mesh::create_grid(1.0, 1.0, 3, 2);
+    assert_eq!(grid.number_of_vertices(), 12);
+    assert_eq!(grid.number_of_faces(), 6);
+    assert_eq!(grid.euler(), 1);
+}
+
+#[test]
+fn test_grid_dimensions_are_mesh_attributes() {
+    let grid = Mesh::create_grid(2.0, 1.0, 2, 1);
+    assert_eq!(grid.attributes.get("grid_x_count"), Some(&2.0));
+    assert_eq!(grid.attributes.get("grid_y_count"), Some(&1.0));
+    assert!(grid.vertex.values().all(|vd| vd.attributes.is_empty()));
+
+    let restored = Mesh::from_compas_json(&grid.to_compas_json()).unwrap();
+    let key = restored.grid_vertex_key(1, 1).unwrap();
+    let p = restored.vertex_position(key).unwrap();
+    assert_eq!((p.x, p.y), (1.0, 1.0));
+    assert!(Mesh::new().grid_vertex_key(0, 0).is_none());
+}
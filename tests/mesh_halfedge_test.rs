@@ -0,0 +1,44 @@
+use openmodel::geometry::Mesh;
+
+mod common;
+
+/// The shared triangle together with its vertex keys in face order and its face key.
+fn triangle() -> (Mesh, [usize; 3], usize) {
+    let mesh = common::triangle();
+    let (&f, vertices) = mesh.face.iter().next().unwrap();
+    let keys = [vertices[0], vertices[1], vertices[2]];
+    (mesh, keys, f)
+}
+
+#[test]
+fn test_halfedge_face() {
+    let (mesh, [v0, v1, v2], f) = triangle();
+    assert_eq!(mesh.halfedge_face(v0, v1), Some(f));
+    assert_eq!(mesh.halfedge_face(v2, v0), Some(f));
+    assert_eq!(mesh.halfedge_face(v1, v0), None);
+    assert_eq!(mesh.halfedge_face(v0, 99), None);
+}
+
+#[test]
+fn test_halfedge_next_and_prev_wrap() {
+    let (mesh, [v0, v1, v2], _) = triangle();
+    assert_eq!(mesh.halfedge_next(v0, v1), Some((v1, v2)));
+    assert_eq!(mesh.halfedge_next(v1, v2), Some((v2, v0)));
+    assert_eq!(mesh.halfedge_next(v2, v0), Some((v0, v1)));
+    assert_eq!(mesh.halfedge_prev(v0, v1), Some((v2, v0)));
+    assert_eq!(mesh.halfedge_prev(v2, v0), Some((v1, v2)));
+
+    // Walking next three times returns to the start
+    let mut he = (v0, v1);
+    for _ in 0..3 {
+        he = mesh.halfedge_next(he.0, he.1).unwrap();
+    }
+    assert_eq!(he, (v0, v1));
+}
+
+#[test]
+fn test_boundary_halfedge_has_no_next() {
+    let (mesh, [v0, v1, _], _) = triangle();
+    assert!(mesh.halfedge_next(v1, v0).is_none());
+    assert!(mesh.halfedge_prev(v1, v0).is_none());
+}
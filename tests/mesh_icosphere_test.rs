@@ -0,0 +1,36 @@
+use openmodel::geometry::{Mesh, Point};
+
+#[test]
+fn test_icosphere_level_zero_is_icosahedron() {
+    let sphere = Mesh::create_icosphere(Point::new(0.0, 0.0, 0.0), 1.0, 0);
+    assert_eq!(sphere.number_of_vertices(), 12);
+    assert_eq!(sphere.number_of_faces(), 20);
+    assert_eq!(sphere.number_of_edges(), 30);
+}
+
+#[test]
+fn test_icosphere_vertices_lie_on_sphere() {
+    let center = Point::new(1.0, -2.0, 3.0);
+    let radius = 2.5;
+    let sphere = Mesh::create_icosphere(center, radius, 2);
+
+    assert_eq!(sphere.number_of_faces(), 320);
+    assert_eq!(sphere.euler(), 2);
+    for vk in sphere.vertex.keys() {
+        let p = sphere.vertex_position(*vk).unwrap();
+        // f32 storage limits this to single precision
+        assert!((p.distance(&center) - radius).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_icosphere_faces_point_outward() {
+    let sphere = Mesh::create_icosphere(Point::new(0.0, 0.0, 0.0), 1.0, 1);
+    let props = sphere.mass_properties().expect("icosphere is closed");
+    assert!(props.volume > 0.0);
+    for (fk, fverts) in &sphere.face {
+        let p = sphere.vertex_position(fverts[0]).unwrap();
+        let n = sphere.face_normal(*fk).unwrap();
+        assert!(n.x * p.x + n.y * p.y + n.z * p.z > 0.0);
+    }
+}
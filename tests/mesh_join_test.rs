@@ -0,0 +1,62 @@
+use openmodel::geometry::{join_meshes, Mesh, Point, Transformable, Xform};
+use std::collections::HashSet;
+
+mod common;
+
+/// The shared triangle moved `offset` along X.
+fn triangle(offset: f32) -> Mesh {
+    common::triangle().transformed(&Xform::translation(offset, 0.0, 0.0))
+}
+
+#[test]
+fn test_join_three_triangles() {
+    let joined = join_meshes(&[triangle(0.0), triangle(2.0), triangle(4.0)]);
+
+    assert_eq!(joined.number_of_vertices(), 9);
+    assert_eq!(joined.number_of_faces(), 3);
+    assert_eq!(joined.vertex.keys().collect::<HashSet<_>>().len(), 9);
+    assert_eq!(joined.face.keys().collect::<HashSet<_>>().len(), 3);
+    // No welding: each triangle keeps its own boundary
+    assert_eq!(joined.edges().len(), 9);
+    for fverts in joined.face.values() {
+        let xs: Vec<f32> = fverts.iter().map(|v| joined.vertex[v].x).collect();
+        assert!(xs.iter().all(|x| (x - xs[0]).abs() <= 1.0));
+    }
+
+    // Fresh keys keep coming after the joined ones
+    let mut joined = joined;
+    let next = joined.add_vertex(Point::new(9.0, 9.0, 9.0), None);
+    assert_eq!(joined.number_of_vertices(), 10);
+    assert!(joined.vertex.contains_key(&next));
+}
+
+#[test]
+fn test_join_keeps_attributes() {
+    let mut tri = triangle(0.0);
+    let fk = *tri.face.keys().next().unwrap();
+    let vk = *tri.vertex.keys().min().unwrap();
+    tri.facedata.entry(fk).or_default().insert("weight".to_string(), 2.0);
+    tri.vertex.get_mut(&vk).unwrap().set_attribute("u", 0.5);
+
+    let joined = join_meshes(&[triangle(5.0), tri]);
+    assert_eq!(joined.facedata.values().filter(|d| d.get("weight") == Some(&2.0)).count(), 1);
+    assert_eq!(joined.vertex.values().filter(|v| v.attributes.get("u") == Some(&0.5)).count(), 1);
+    assert!(join_meshes(&[]).is_empty());
+}
+
+#[test]
+fn test_merge_two_unit_triangles() {
+    let mut mesh = triangle(0.0);
+    let other = triangle(0.0);
+    mesh.merge(&other);
+    assert_eq!(mesh.number_of_vertices(), 6);
+    assert_eq!(mesh.number_of_faces(), 2);
+    assert_eq!(mesh.number_of_edges(), 6);
+
+    // New keys continue after the merged ones
+    let vkeys: HashSet<usize> = mesh.vertex.keys().copied().collect();
+    let next = mesh.add_vertex(Point::new(9.0, 9.0, 9.0), None);
+    assert!(!vkeys.contains(&next));
+    let fkeys: HashSet<usize> = mesh.face.keys().copied().collect();
+    assert!(!fkeys.contains(&mesh.reserve_face_key()));
+}
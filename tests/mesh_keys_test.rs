@@ -0,0 +1,44 @@
+use openmodel::geometry::{Mesh, Point};
+
+#[test]
+fn test_reserved_vertex_keys_do_not_collide() {
+    let mut mesh = Mesh::new();
+    let first = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let reserved: Vec<usize> = (0..5).map(|_| mesh.reserve_vertex_key()).collect();
+    assert!(reserved.windows(2).all(|w| w[0] < w[1]));
+    assert!(reserved[0] > first);
+
+    let auto: Vec<usize> = (0..5)
+        .map(|i| mesh.add_vertex(Point::new(i as f32, 1.0, 0.0), None))
+        .collect();
+    for key in &auto {
+        assert!(!reserved.contains(key));
+    }
+
+    // Reserved keys can be filled in later without overwriting anything
+    for (i, key) in reserved.iter().enumerate() {
+        assert_eq!(mesh.add_vertex(Point::new(i as f32, 2.0, 0.0), Some(*key)), *key);
+    }
+    assert_eq!(mesh.number_of_vertices(), 11);
+
+    let next = mesh.add_vertex(Point::new(0.0, 3.0, 0.0), None);
+    assert!(!reserved.contains(&next) && !auto.contains(&next));
+}
+
+#[test]
+fn test_reserved_face_keys_do_not_collide() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+
+    let r1 = mesh.reserve_face_key();
+    let r2 = mesh.reserve_face_key();
+    assert!(r1 < r2);
+
+    let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    assert!(f != r1 && f != r2);
+    assert_eq!(mesh.add_face(vec![a, c, d], Some(r1)), Some(r1));
+    assert_eq!(mesh.number_of_faces(), 2);
+}
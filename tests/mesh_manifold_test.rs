@@ -0,0 +1,40 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_cube_and_grid_are_manifold() {
+    assert!(unit_cube().is_manifold());
+    assert!(Mesh::create_grid(3.0, 2.0, 3, 2).is_manifold());
+    assert!(Mesh::new().is_manifold());
+}
+
+#[test]
+fn test_three_triangles_on_one_edge() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(0.0, 0.0, 1.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(-1.0, 1.0, 0.0), None);
+    let e = mesh.add_vertex(Point::new(-1.0, -1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    mesh.add_face(vec![b, a, d], None);
+    assert!(mesh.is_manifold());
+    mesh.add_face(vec![a, b, e], None);
+    assert!(!mesh.is_manifold());
+}
+
+#[test]
+fn test_bowtie_vertex_is_not_manifold() {
+    // Two triangles touching only at a single vertex
+    let mut mesh = Mesh::new();
+    let o = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let a = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(-1.0, 0.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(-1.0, -1.0, 0.0), None);
+    mesh.add_face(vec![o, a, b], None);
+    mesh.add_face(vec![o, c, d], None);
+    assert!(!mesh.is_manifold());
+}
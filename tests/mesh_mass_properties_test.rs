@@ -0,0 +1,50 @@
+use openmodel::geometry::{Mesh, Point};
+use openmodel::primitives::{Transformable, Xform};
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_mass_properties_unit_cube() {
+    let mesh = unit_cube();
+    let props = mesh.mass_properties().expect("cube is closed");
+
+    assert!((props.volume - 1.0).abs() < 1e-5);
+    assert!((props.centroid.x - 0.5).abs() < 1e-5);
+    assert!((props.centroid.y - 0.5).abs() < 1e-5);
+    assert!((props.centroid.z - 0.5).abs() < 1e-5);
+
+    // Solid cube about its center: I = m * (a^2 + a^2) / 12 = 1/6 on the diagonal
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = if i == j { 1.0 / 6.0 } else { 0.0 };
+            assert!((props.inertia[i][j] - expected).abs() < 1e-5, "inertia[{}][{}] = {}", i, j, props.inertia[i][j]);
+        }
+    }
+}
+
+#[test]
+fn test_mass_properties_open_mesh_is_none() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c, d], None);
+    assert!(mesh.mass_properties().is_none());
+}
+
+#[test]
+fn test_volume_unit_cube_anywhere() {
+    let mesh = unit_cube();
+    assert!((mesh.volume() - 1.0).abs() < 1e-5);
+
+    let moved = mesh.transformed(&Xform::translation(12.5, -7.0, 3.25));
+    assert!((moved.volume() - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_volume_scaled_cube() {
+    let mesh = unit_cube().transformed(&Xform::scaling(2.0, 2.0, 2.0));
+    assert!((mesh.volume() - 8.0).abs() < 1e-4);
+}
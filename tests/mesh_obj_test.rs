@@ -0,0 +1,56 @@
+use openmodel::geometry::Mesh;
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_cube_to_obj() {
+    let obj = unit_cube().to_obj();
+    let v_lines: Vec<&str> = obj.lines().filter(|l| l.starts_with("v ")).collect();
+    let f_lines: Vec<&str> = obj.lines().filter(|l| l.starts_with("f ")).collect();
+
+    assert_eq!(v_lines.len(), 8);
+    assert_eq!(f_lines.len(), 6);
+    for f in &f_lines {
+        let indices: Vec<usize> = f[2..].split_whitespace().map(|i| i.parse().unwrap()).collect();
+        assert_eq!(indices.len(), 4);
+        assert!(indices.iter().all(|i| (1..=8).contains(i)));
+    }
+}
+
+#[test]
+fn test_write_obj() {
+    let path = std::env::temp_dir().join("openmodel_mesh_obj_test.obj");
+    let path = path.to_str().unwrap();
+    let cube = unit_cube();
+    cube.write_obj(path).unwrap();
+    assert_eq!(std::fs::read_to_string(path).unwrap(), cube.to_obj());
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_obj_roundtrip() {
+    let cube = unit_cube();
+    let mesh = Mesh::from_obj(&cube.to_obj()).unwrap();
+    assert_eq!(mesh.number_of_vertices(), cube.number_of_vertices());
+    assert_eq!(mesh.number_of_faces(), cube.number_of_faces());
+    assert_eq!(mesh.to_obj(), cube.to_obj());
+}
+
+#[test]
+fn test_from_obj_slashes_and_negative_indices() {
+    let text = "# quad\nv 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nvn 0 0 1\nf 1/1/1 2//1 -2/3 -1\n";
+    let mesh = Mesh::from_obj(text).unwrap();
+    assert_eq!(mesh.number_of_vertices(), 4);
+    assert_eq!(mesh.number_of_faces(), 1);
+    let face = mesh.face_vertices(*mesh.face.keys().next().unwrap()).unwrap();
+    assert_eq!(face.len(), 4);
+}
+
+#[test]
+fn test_from_obj_malformed() {
+    assert!(matches!(Mesh::from_obj("v 0 0\n"), Err(openmodel::Error::Parse(_))));
+    assert!(matches!(Mesh::from_obj("v 0 0 x\n"), Err(openmodel::Error::Parse(_))));
+    assert!(matches!(Mesh::from_obj("v 0 0 0\nf 1 2 3\n"), Err(openmodel::Error::Parse(_))));
+    assert!(matches!(Mesh::from_obj("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 0\n"), Err(openmodel::Error::Parse(_))));
+}
@@ -0,0 +1,121 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube_polygons;
+
+/// Icosphere rebuilt from polygons with every other face reversed.
+fn scrambled_sphere(center: Point) -> Mesh {
+    let sphere = Mesh::create_icosphere(center, 1.0, 1);
+    let mut fkeys: Vec<usize> = sphere.face.keys().copied().collect();
+    fkeys.sort_unstable();
+    let polygons: Vec<Vec<Point>> = fkeys
+        .iter()
+        .enumerate()
+        .map(|(i, fk)| {
+            let mut polygon: Vec<Point> = sphere.face[fk]
+                .iter()
+                .map(|vk| sphere.vertex_position(*vk).unwrap())
+                .collect();
+            if i % 2 == 0 {
+                polygon.reverse();
+            }
+            polygon
+        })
+        .collect();
+    Mesh::from_polygons(polygons, None)
+}
+
+fn all_faces_point_away(mesh: &Mesh, reference: &Point) -> bool {
+    mesh.face.iter().all(|(fk, fverts)| {
+        let p = mesh.vertex_position(fverts[0]).unwrap();
+        let n = mesh.face_normal(*fk).unwrap();
+        n.x * (p.x - reference.x) + n.y * (p.y - reference.y) + n.z * (p.z - reference.z) > 0.0
+    })
+}
+
+#[test]
+fn test_orient_faces_toward_sphere_center() {
+    let center = Point::new(2.0, 1.0, -1.0);
+    let mut mesh = scrambled_sphere(center);
+    assert!(!all_faces_point_away(&mesh, &center));
+
+    mesh.orient_faces_toward(&center);
+    assert!(all_faces_point_away(&mesh, &center));
+
+    // Halfedges are consistent again: the sphere is closed with positive volume
+    assert!(mesh.naked_vertices().is_empty());
+    assert!(mesh.mass_properties().expect("closed sphere").volume > 0.0);
+}
+
+#[test]
+fn test_orient_faces_toward_keeps_consistent_mesh() {
+    let center = Point::new(0.0, 0.0, 0.0);
+    let mut mesh = Mesh::create_icosphere(center, 1.0, 1);
+    let before = mesh.face.clone();
+    mesh.orient_faces_toward(&center);
+    assert_eq!(mesh.face, before);
+}
+
+fn unit_cube_with_reversed_face() -> Mesh {
+    let mut polygons = unit_cube_polygons();
+    polygons[3].reverse();
+    Mesh::from_polygons(polygons, None)
+}
+
+#[test]
+fn test_flip_normals_negates_face_normal() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    let before = mesh.face_normal(f).unwrap();
+    mesh.flip_normals();
+    let after = mesh.face_normal(f).unwrap();
+    assert_eq!((after.x, after.y, after.z), (-before.x, -before.y, -before.z));
+    assert_eq!(mesh.halfedge[&b][&a], Some(f));
+    assert_eq!(mesh.halfedge[&a][&b], None);
+}
+
+#[test]
+fn test_unify_cycles_fixes_reversed_cube_face() {
+    let mut cube = unit_cube_with_reversed_face();
+    assert!((cube.volume() - 1.0).abs() > 0.1);
+
+    assert!(cube.unify_cycles());
+    assert!((cube.volume() - 1.0).abs() < 1e-5);
+    assert!(cube.naked_vertices().is_empty());
+    assert!(all_faces_point_away(&cube, &Point::new(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn test_unify_cycles_scrambled_sphere() {
+    let mut sphere = scrambled_sphere(Point::new(0.0, 0.0, 0.0));
+    assert!(sphere.unify_cycles());
+    if sphere.volume() < 0.0 {
+        sphere.flip_normals();
+    }
+    assert!(all_faces_point_away(&sphere, &Point::new(0.0, 0.0, 0.0)));
+}
+
+#[test]
+fn test_unify_cycles_mobius_strip_is_non_orientable() {
+    let n = 6;
+    let mut mesh = Mesh::new();
+    let mut a = Vec::new();
+    let mut c = Vec::new();
+    for i in 0..n {
+        let t = i as f32 / n as f32 * std::f32::consts::TAU;
+        a.push(mesh.add_vertex(Point::new(t.cos() * 2.0, t.sin() * 2.0, -0.5), None));
+        c.push(mesh.add_vertex(Point::new(t.cos() * 2.0, t.sin() * 2.0, 0.5), None));
+    }
+    for i in 0..n - 1 {
+        mesh.add_face(vec![a[i], a[i + 1], c[i + 1], c[i]], None);
+    }
+    // The half twist joins the bottom edge to the top edge
+    mesh.add_face(vec![a[n - 1], c[0], a[0], c[n - 1]], None);
+
+    let faces_before = mesh.face.clone();
+    assert!(!mesh.unify_cycles());
+    assert_eq!(mesh.face, faces_before);
+}
@@ -0,0 +1,32 @@
+use openmodel::geometry::{Mesh, Point};
+
+#[test]
+fn test_triangle_is_planar() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 1.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 2.0, 3.0), None);
+    let f = mesh.add_face(vec![a, b, c], None).unwrap();
+
+    assert!(mesh.face_planarity(f).unwrap() < 1e-6);
+    let plane = mesh.face_plane(f).unwrap();
+    assert!(plane.zaxis.dot(&mesh.face_normal(f).unwrap()) > 0.999);
+}
+
+#[test]
+fn test_warped_quad_deviation() {
+    // Unit square with corners alternately lifted and lowered by h
+    let h = 0.1;
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, h), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, -h), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, h), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, -h), None);
+    let f = mesh.add_face(vec![a, b, c, d], None).unwrap();
+
+    let plane = mesh.face_plane(f).unwrap();
+    assert!(plane.origin.distance(&Point::new(0.5, 0.5, 0.0)) < 1e-6);
+    assert!((mesh.face_planarity(f).unwrap() - h).abs() < 1e-3);
+    assert!(mesh.face_plane(99).is_none());
+    assert!(mesh.face_planarity(99).is_none());
+}
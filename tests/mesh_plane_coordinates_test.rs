@@ -0,0 +1,25 @@
+use openmodel::geometry::{Mesh, Plane, Point, Vector};
+
+#[test]
+fn test_face_on_tilted_plane_becomes_flat() {
+    let plane = Plane::from_point_normal(&Point::new(1.0, 2.0, 3.0), &Vector::new(1.0, -1.0, 2.0));
+    let mut mesh = Mesh::new();
+    let keys: Vec<usize> = [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.5)]
+        .iter()
+        .map(|(u, v)| mesh.add_vertex(plane.from_uv(*u, *v), None))
+        .collect();
+    mesh.add_face(keys.clone(), None);
+
+    let local = mesh.to_plane_coordinates(&plane);
+    for (k, (u, v)) in keys.iter().zip([(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.5)]) {
+        let p = local.vertex_position(*k).unwrap();
+        assert!(p.z.abs() < 1e-5);
+        assert!((p.x - u).abs() < 1e-5 && (p.y - v).abs() < 1e-5);
+    }
+    assert_eq!(local.face, mesh.face);
+
+    let back = local.from_plane_coordinates(&plane);
+    for k in &keys {
+        assert!(back.vertex_position(*k).unwrap().distance(&mesh.vertex_position(*k).unwrap()) < 1e-5);
+    }
+}
@@ -0,0 +1,37 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::two_triangles;
+
+#[test]
+fn test_precision_one_merges_within_a_unit() {
+    let mesh = Mesh::from_polygons(two_triangles(0.3), Some(1.0));
+    assert_eq!(mesh.number_of_vertices(), 4);
+    assert_eq!(mesh.number_of_faces(), 2);
+}
+
+#[test]
+fn test_fine_precision_keeps_close_points_distinct() {
+    let mesh = Mesh::from_polygons(two_triangles(0.01), Some(1e-3));
+    assert_eq!(mesh.number_of_vertices(), 6);
+}
+
+#[test]
+fn test_precision_for_large_coordinates() {
+    let polygons = vec![
+        vec![Point::new(1.0e5, 0.0, 0.0), Point::new(1.0e5 + 1.0, 0.0, 0.0), Point::new(1.0e5, 1.0, 0.0)],
+        vec![Point::new(1.0e5 + 1.0, 0.0, 0.0), Point::new(1.0e5 + 1.0, 1.0, 0.0), Point::new(1.0e5, 1.0, 0.0)],
+    ];
+    let mesh = Mesh::from_polygons(polygons, Some(1e-2));
+    assert_eq!(mesh.number_of_vertices(), 4);
+}
+
+#[test]
+fn test_exact_merge_treats_signed_zero_as_equal() {
+    let polygons = vec![
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)],
+        vec![Point::new(-0.0, 0.0, -0.0), Point::new(0.0, -1.0, 0.0), Point::new(1.0, 0.0, 0.0)],
+    ];
+    let mesh = Mesh::from_polygons(polygons, None);
+    assert_eq!(mesh.number_of_vertices(), 4);
+}
@@ -0,0 +1,112 @@
+use openmodel::geometry::{Mesh, Point};
+
+#[test]
+fn test_merge_coplanar_triangles_into_quad() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    mesh.add_face(vec![a, c, d], None);
+
+    assert_eq!(mesh.merge_coplanar_triangles(1e-3), 1);
+    assert_eq!(mesh.number_of_faces(), 1);
+    assert_eq!(mesh.number_of_edges(), 4);
+
+    let (fk, fverts) = mesh.face.iter().next().unwrap();
+    assert_eq!(fverts.len(), 4);
+    assert!(mesh.face_normal(*fk).unwrap().z > 0.0);
+    // The diagonal is gone from the connectivity
+    assert!(mesh.edge_faces(a, c).is_empty());
+    assert_eq!(mesh.edge_faces(a, b), vec![*fk]);
+}
+
+#[test]
+fn test_merge_skips_folded_and_non_convex_pairs() {
+    // Folded pair: 90 degree dihedral angle
+    let mut folded = Mesh::new();
+    let a = folded.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = folded.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = folded.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let d = folded.add_vertex(Point::new(0.0, 0.0, 1.0), None);
+    folded.add_face(vec![a, b, c], None);
+    folded.add_face(vec![b, a, d], None);
+    assert_eq!(folded.merge_coplanar_triangles(0.1), 0);
+    assert_eq!(folded.number_of_faces(), 2);
+
+    // Coplanar dart: merging would produce a reflex corner
+    let mut dart = Mesh::new();
+    let a = dart.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = dart.add_vertex(Point::new(2.0, 0.0, 0.0), None);
+    let c = dart.add_vertex(Point::new(0.5, 0.5, 0.0), None);
+    let d = dart.add_vertex(Point::new(0.0, 2.0, 0.0), None);
+    dart.add_face(vec![a, b, c], None);
+    dart.add_face(vec![a, c, d], None);
+    assert_eq!(dart.merge_coplanar_triangles(0.1), 0);
+    assert_eq!(dart.number_of_faces(), 2);
+}
+
+#[test]
+fn test_merge_grid_triangles() {
+    // Triangulated 3x3 grid: every square merges back into a quad
+    let mut mesh = Mesh::new();
+    let mut keys = Vec::new();
+    for j in 0..4 {
+        for i in 0..4 {
+            keys.push(mesh.add_vertex(Point::new(i as f32, j as f32, 0.0), None));
+        }
+    }
+    for j in 0..3 {
+        for i in 0..3 {
+            let (p, q, r, s) = (keys[j * 4 + i], keys[j * 4 + i + 1], keys[(j + 1) * 4 + i + 1], keys[(j + 1) * 4 + i]);
+            mesh.add_face(vec![p, q, r], None);
+            mesh.add_face(vec![p, r, s], None);
+        }
+    }
+    assert_eq!(mesh.merge_coplanar_triangles(1e-3), 9);
+    assert_eq!(mesh.number_of_faces(), 9);
+    assert!(mesh.face.values().all(|fverts| fverts.len() == 4));
+    assert_eq!(mesh.euler(), 1);
+}
+
+#[test]
+fn test_tri_to_quad_on_grid_with_alternating_diagonals() {
+    // Triangulated 4x4 grid whose diagonals alternate direction, with a gentle bump
+    let n = 4;
+    let mut mesh = Mesh::new();
+    let mut keys = Vec::new();
+    for j in 0..=n {
+        for i in 0..=n {
+            let z = 0.05 * ((i as f32) * 0.7).sin() * ((j as f32) * 0.5).cos();
+            keys.push(mesh.add_vertex(Point::new(i as f32, j as f32, z), None));
+        }
+    }
+    for j in 0..n {
+        for i in 0..n {
+            let (p, q, r, s) = (keys[j * (n + 1) + i], keys[j * (n + 1) + i + 1], keys[(j + 1) * (n + 1) + i + 1], keys[(j + 1) * (n + 1) + i]);
+            if (i + j) % 2 == 0 {
+                mesh.add_face(vec![p, q, r], None);
+                mesh.add_face(vec![p, r, s], None);
+            } else {
+                mesh.add_face(vec![p, q, s], None);
+                mesh.add_face(vec![q, r, s], None);
+            }
+        }
+    }
+
+    let quads = mesh.tri_to_quad(0.2);
+    let quad_count = quads.face.values().filter(|f| f.len() == 4).count();
+    assert_eq!(quad_count, n * n);
+    assert_eq!(quads.number_of_faces(), n * n);
+    // Every quad is a grid cell: all corners close to right angles
+    for fk in quads.face.keys() {
+        assert!(quads.face_angles(*fk).iter().all(|a| (a - std::f32::consts::FRAC_PI_2).abs() < 0.1));
+    }
+    // Vertices are untouched and the input mesh is unchanged
+    assert_eq!(quads.number_of_vertices(), mesh.number_of_vertices());
+    for (k, v) in &mesh.vertex {
+        assert_eq!((quads.vertex[k].x, quads.vertex[k].y, quads.vertex[k].z), (v.x, v.y, v.z));
+    }
+    assert_eq!(mesh.number_of_faces(), 2 * n * n);
+}
@@ -0,0 +1,81 @@
+use openmodel::geometry::{Mesh, Point};
+
+/// Two triangles sharing the edge (b, c).
+fn two_triangles() -> (Mesh, [usize; 4], [usize; 2]) {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let f1 = mesh.add_face(vec![a, b, c], None).unwrap();
+    let f2 = mesh.add_face(vec![b, d, c], None).unwrap();
+    (mesh, [a, b, c, d], [f1, f2])
+}
+
+#[test]
+fn test_remove_face_updates_boundary() {
+    let (mut mesh, [a, b, c, d], [f1, f2]) = two_triangles();
+    assert!(!mesh.is_edge_on_boundary(b, c));
+    assert_eq!(mesh.number_of_edges(), 5);
+
+    assert!(mesh.remove_face(f2));
+    assert_eq!(mesh.number_of_faces(), 1);
+    assert_eq!(mesh.number_of_edges(), 3);
+    // The shared edge is now on the boundary, the others of f2 are gone
+    assert!(mesh.is_edge_on_boundary(b, c));
+    assert_eq!(mesh.halfedge[&c].get(&b), Some(&None));
+    assert_eq!(mesh.halfedge[&b].get(&c), Some(&Some(f1)));
+    assert!(!mesh.halfedge[&b].contains_key(&d));
+    assert!(!mesh.halfedge[&d].contains_key(&c));
+    assert!(!mesh.is_vertex_on_boundary(d));
+    assert_eq!(mesh.isolated_vertices(), vec![d]);
+    assert_eq!(mesh.boundary_loops(), vec![vec![a, c, b]]);
+}
+
+#[test]
+fn test_remove_face_missing_key() {
+    let (mut mesh, _, [f1, f2]) = two_triangles();
+    assert!(!mesh.remove_face(f1 + f2 + 100));
+    assert_eq!(mesh.number_of_faces(), 2);
+}
+
+#[test]
+fn test_remove_face_drops_face_and_edge_data() {
+    let (mut mesh, [_, b, c, d], [_, f2]) = two_triangles();
+    mesh.facedata.insert(f2, Default::default());
+    mesh.set_edge_crease(b, c, 1.0);
+    mesh.set_edge_crease(c, d, 1.0);
+    mesh.remove_face(f2);
+    assert!(!mesh.facedata.contains_key(&f2));
+    assert_eq!(mesh.edge_crease(b, c), 1.0);
+    assert_eq!(mesh.edge_crease(c, d), 0.0);
+}
+
+#[test]
+fn test_remove_isolated_vertex() {
+    let (mut mesh, _, _) = two_triangles();
+    let e = mesh.add_vertex(Point::new(5.0, 5.0, 0.0), None);
+    assert!(mesh.remove_vertex(e));
+    assert_eq!(mesh.number_of_vertices(), 4);
+    assert!(!mesh.halfedge.contains_key(&e));
+    assert!(mesh.vertex_position(e).is_none());
+}
+
+#[test]
+fn test_remove_vertex_in_face_is_rejected() {
+    let (mut mesh, [a, _, _, _], _) = two_triangles();
+    assert!(!mesh.remove_vertex(a));
+    assert_eq!(mesh.number_of_vertices(), 4);
+    assert_eq!(mesh.number_of_faces(), 2);
+}
+
+#[test]
+fn test_remove_vertex_after_removing_its_faces() {
+    let (mut mesh, [_, b, c, d], [_, f2]) = two_triangles();
+    assert!(!mesh.remove_vertex(d));
+    mesh.remove_face(f2);
+    assert!(mesh.remove_vertex(d));
+    assert!(mesh.halfedge.values().all(|neigh| !neigh.contains_key(&d)));
+    assert_eq!(mesh.vertex_neighbors(b).len(), 2);
+    assert_eq!(mesh.vertex_neighbors(c).len(), 2);
+}
@@ -0,0 +1,47 @@
+use openmodel::geometry::{Mesh, Point};
+use std::collections::HashMap;
+
+#[test]
+fn test_shell_with_one_thick_vertex() {
+    let grid = Mesh::create_grid(2.0, 2.0, 2, 2);
+    let center = grid.grid_vertex_key(1, 1).unwrap();
+    let thickness = HashMap::from([(center, 0.5)]);
+    let shell = grid.shell(&thickness, 0.1);
+
+    assert_eq!(shell.number_of_vertices(), 2 * grid.number_of_vertices());
+    // Both layers plus one quad per boundary edge
+    assert_eq!(shell.number_of_faces(), 2 * 4 + 8);
+    assert!(shell.naked_vertices().is_empty());
+    assert_eq!(shell.euler(), 2);
+
+    // Each original vertex has an offset partner along +Z at its mapped distance
+    for vk in grid.vertex.keys() {
+        let p = grid.vertex_position(*vk).unwrap();
+        let expected = if *vk == center { 0.5 } else { 0.1 };
+        let lifted = Point::new(p.x, p.y, p.z + expected);
+        let found = shell
+            .vertex
+            .keys()
+            .any(|sk| shell.vertex_position(*sk).unwrap().distance(&lifted) < 1e-6);
+        assert!(found, "no offset vertex for {}", vk);
+    }
+
+    // The shell is a consistently oriented solid
+    let props = shell.mass_properties().expect("closed shell");
+    assert!(props.volume > 0.0);
+}
+
+#[test]
+fn test_vertex_normal_and_face_area() {
+    let grid = Mesh::create_grid(2.0, 1.0, 2, 1);
+    for fk in grid.face.keys() {
+        assert!((grid.face_area(*fk).unwrap() - 1.0).abs() < 1e-6);
+    }
+    let corner = grid.grid_vertex_key(0, 0).unwrap();
+    let middle = grid.grid_vertex_key(1, 0).unwrap();
+    assert_eq!(grid.vertex_faces(corner).len(), 1);
+    assert_eq!(grid.vertex_faces(middle).len(), 2);
+    assert_eq!(grid.vertex_neighbors(middle).len(), 3);
+    let n = grid.vertex_normal(middle).unwrap();
+    assert!((n.z.abs() - 1.0).abs() < 1e-6);
+}
@@ -0,0 +1,58 @@
+use openmodel::geometry::Mesh;
+
+fn interior_height_variance(mesh: &Mesh) -> f32 {
+    let heights: Vec<f32> = mesh
+        .vertex
+        .keys()
+        .filter(|vk| !mesh.is_vertex_on_boundary(**vk))
+        .map(|vk| mesh.vertex_position(*vk).unwrap().z)
+        .collect();
+    let n = heights.len() as f32;
+    let mean = heights.iter().sum::<f32>() / n;
+    heights.iter().map(|h| (h - mean).powi(2)).sum::<f32>() / n
+}
+
+fn noisy_grid() -> Mesh {
+    let mut grid = Mesh::create_grid(10.0, 10.0, 10, 10);
+    // Deterministic pseudo-random heights in [-0.5, 0.5]
+    let mut seed: u32 = 12345;
+    let mut keys: Vec<usize> = grid.vertex.keys().copied().collect();
+    keys.sort_unstable();
+    for vk in keys {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let noise = (seed >> 16) as f32 / 65535.0 - 0.5;
+        grid.vertex.get_mut(&vk).unwrap().z = noise;
+    }
+    grid
+}
+
+#[test]
+fn test_smoothing_reduces_interior_variance() {
+    let mut grid = noisy_grid();
+    let before = interior_height_variance(&grid);
+    grid.smooth_laplacian(5, 0.5);
+    let after = interior_height_variance(&grid);
+    assert!(after < before * 0.5, "variance {} -> {}", before, after);
+}
+
+#[test]
+fn test_smoothing_keeps_boundary_fixed() {
+    let mut grid = noisy_grid();
+    let boundary: Vec<(usize, f32)> = grid
+        .naked_vertices()
+        .into_iter()
+        .map(|vk| (vk, grid.vertex_position(vk).unwrap().z))
+        .collect();
+    grid.smooth_laplacian(3, 1.0);
+    for (vk, z) in boundary {
+        assert_eq!(grid.vertex_position(vk).unwrap().z, z);
+    }
+}
+
+#[test]
+fn test_zero_lambda_is_identity() {
+    let mut grid = noisy_grid();
+    let before = grid.to_vertices_and_faces().0;
+    grid.smooth_laplacian(4, 0.0);
+    assert_eq!(grid.to_vertices_and_faces().0, before);
+}
@@ -0,0 +1,33 @@
+use openmodel::geometry::Point;
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_restore_returns_prior_state() {
+    let mut mesh = unit_cube();
+    let guid = mesh.data.guid();
+    let before = mesh.geometric_data();
+    let snapshot = mesh.snapshot();
+
+    let vkey = *mesh.vertex.keys().min().unwrap();
+    let fkey = *mesh.face.keys().min().unwrap();
+    mesh.vertex.get_mut(&vkey).unwrap().set_position(Point::new(-1.0, -1.0, -1.0));
+    assert!(mesh.remove_face(fkey));
+    assert_ne!(mesh.geometric_data(), before);
+
+    mesh.restore(snapshot);
+    assert_eq!(mesh.geometric_data(), before);
+    assert_eq!(mesh.data.guid(), guid);
+}
+
+#[test]
+fn test_restore_keeps_key_counters() {
+    let mut mesh = unit_cube();
+    let snapshot = mesh.snapshot();
+    let next = mesh.add_vertex(Point::new(2.0, 0.0, 0.0), None);
+
+    mesh.restore(snapshot);
+    assert!(!mesh.vertex.contains_key(&next));
+    assert_eq!(mesh.add_vertex(Point::new(2.0, 0.0, 0.0), None), next);
+}
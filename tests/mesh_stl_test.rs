@@ -0,0 +1,41 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::triangle;
+
+fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+#[test]
+fn test_triangle_stl_ascii() {
+    let stl = triangle().to_stl_ascii();
+    assert_eq!(stl.matches("facet normal").count(), 1);
+    assert_eq!(stl.matches("endfacet").count(), 1);
+    assert_eq!(stl.matches("vertex ").count(), 3);
+    assert!(stl.contains("facet normal 0 0 1"));
+    assert!(stl.lines().last().unwrap().starts_with("endsolid"));
+}
+
+#[test]
+fn test_triangle_stl_binary() {
+    let bytes = triangle().to_stl_binary();
+    assert_eq!(bytes.len(), 80 + 4 + 50);
+    assert_eq!(u32::from_le_bytes(bytes[80..84].try_into().unwrap()), 1);
+    // Normal followed by the three vertices
+    assert_eq!((read_f32(&bytes, 84), read_f32(&bytes, 88), read_f32(&bytes, 92)), (0.0, 0.0, 1.0));
+    assert_eq!(read_f32(&bytes, 84 + 12 + 12), 1.0);
+    assert_eq!(&bytes[132..134], &[0, 0]);
+}
+
+#[test]
+fn test_quad_is_split_into_two_facets() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 1.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c, d], None);
+    assert_eq!(mesh.to_stl_ascii().matches("facet normal 0 0 1").count(), 2);
+    assert_eq!(mesh.to_stl_binary().len(), 84 + 2 * 50);
+}
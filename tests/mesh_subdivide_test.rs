@@ -0,0 +1,124 @@
+use openmodel::geometry::mesh::SubdivScheme;
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::{triangle, unit_cube};
+
+fn quad() -> Mesh {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(2.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(2.0, 2.0, 0.0), None);
+    let d = mesh.add_vertex(Point::new(0.0, 2.0, 0.0), None);
+    mesh.add_face(vec![a, b, c, d], None);
+    mesh
+}
+
+#[test]
+fn test_linear_quad_yields_four_quads() {
+    let fine = quad().subdivide(SubdivScheme::Linear, 1);
+    assert_eq!(fine.number_of_faces(), 4);
+    assert_eq!(fine.number_of_vertices(), 9);
+    assert!(fine.face.values().all(|fverts| fverts.len() == 4));
+    // Linear keeps the original corners in place
+    assert!(fine.vertex.keys().any(|vk| fine.vertex_position(*vk) == Some(Point::new(2.0, 2.0, 0.0))));
+    assert!(fine.face.keys().all(|fk| fine.face_normal(*fk).unwrap().z > 0.0));
+}
+
+#[test]
+fn test_loop_triangle_yields_four_triangles() {
+    let fine = triangle().subdivide(SubdivScheme::Loop, 1);
+    assert_eq!(fine.number_of_faces(), 4);
+    assert_eq!(fine.number_of_vertices(), 6);
+    assert!(fine.face.values().all(|fverts| fverts.len() == 3));
+    assert!(fine.face.keys().all(|fk| fine.face_normal(*fk).unwrap().z > 0.0));
+
+    let finer = triangle().subdivide(SubdivScheme::Loop, 2);
+    assert_eq!(finer.number_of_faces(), 16);
+}
+
+#[test]
+fn test_loop_triangulates_quads_first() {
+    let fine = quad().subdivide(SubdivScheme::Loop, 1);
+    assert_eq!(fine.number_of_faces(), 8);
+    assert!(fine.face.values().all(|fverts| fverts.len() == 3));
+}
+
+#[test]
+fn test_catmull_clark_cube_stays_closed_and_shrinks() {
+    let cube = unit_cube();
+    let smooth = cube.subdivide(SubdivScheme::CatmullClark, 1);
+    assert_eq!(smooth.number_of_faces(), 24);
+    assert_eq!(smooth.number_of_vertices(), 26);
+    assert_eq!(smooth.euler(), 2);
+    assert!(smooth.naked_vertices().is_empty());
+
+    let volume = smooth.mass_properties().expect("closed").volume;
+    assert!(volume > 0.0 && volume < 1.0);
+}
+
+#[test]
+fn test_zero_iterations_returns_copy() {
+    let cube = unit_cube();
+    for scheme in [SubdivScheme::Linear, SubdivScheme::CatmullClark, SubdivScheme::Loop] {
+        let same = cube.subdivide(scheme, 0);
+        assert_eq!(same.number_of_faces(), 6);
+    }
+}
+
+#[test]
+fn test_catmull_clark_fully_creased_cube_keeps_silhouette() {
+    let mut cube = unit_cube();
+    for (u, v) in cube.edges() {
+        assert!(cube.set_edge_crease(u, v, 1.0));
+    }
+    let sharp = cube.subdivide(SubdivScheme::CatmullClark, 2);
+    assert_eq!(sharp.number_of_faces(), 96);
+
+    // Every vertex stays on the surface of the unit cube
+    for vk in sharp.vertex.keys() {
+        let p = sharp.vertex_position(*vk).unwrap();
+        let coords = [p.x, p.y, p.z];
+        assert!(coords.iter().all(|c| (-1e-5..=1.0 + 1e-5).contains(c)));
+        assert!(coords.iter().any(|c| c.abs() < 1e-5 || (c - 1.0).abs() < 1e-5), "{:?} is off the cube", coords);
+    }
+    let volume = sharp.mass_properties().expect("closed").volume;
+    assert!((volume - 1.0).abs() < 1e-4);
+
+    let rounded = unit_cube().subdivide(SubdivScheme::CatmullClark, 2);
+    assert!(rounded.mass_properties().expect("closed").volume < 0.9);
+}
+
+#[test]
+fn test_catmull_clark_crease_on_one_edge() {
+    let mut cube = unit_cube();
+    let (u, v) = cube.edges()[0];
+    cube.set_edge_crease(u, v, 1.0);
+    let fine = cube.subdivide(SubdivScheme::CatmullClark, 1);
+    // The creased edge's midpoint stays on the original edge
+    let (pu, pv) = (cube.vertex_position(u).unwrap(), cube.vertex_position(v).unwrap());
+    let mid = Point::new((pu.x + pv.x) / 2.0, (pu.y + pv.y) / 2.0, (pu.z + pv.z) / 2.0);
+    assert!(fine.vertex.keys().any(|vk| fine.vertex_position(*vk).unwrap().distance(&mid) < 1e-6));
+    assert_eq!(fine.edgedata.values().filter(|attrs| attrs.get("crease") == Some(&1.0)).count(), 2);
+}
+
+#[test]
+fn test_catmull_clark_face_counts_follow_face_degree() {
+    let cube = unit_cube();
+    let once = cube.subdivide_catmull_clark(1);
+    assert_eq!(once.number_of_faces(), 24);
+    assert!(once.face.values().all(|fverts| fverts.len() == 4));
+    assert_eq!(cube.subdivide_catmull_clark(2).number_of_faces(), 96);
+
+    // A triangle and a pentagon side by side: 3 + 5 quads
+    let mut mesh = Mesh::new();
+    let p: Vec<usize> = [(0.0, 0.0), (1.0, 0.0), (1.5, 1.0), (0.5, 1.5), (-0.5, 1.0), (-1.0, 0.0)]
+        .iter()
+        .map(|(x, y)| mesh.add_vertex(Point::new(*x, *y, 0.0), None))
+        .collect();
+    mesh.add_face(vec![p[0], p[1], p[2], p[3], p[4]], None);
+    mesh.add_face(vec![p[0], p[4], p[5]], None);
+    let fine = mesh.subdivide_catmull_clark(1);
+    assert_eq!(fine.number_of_faces(), 8);
+    assert!(fine.face.values().all(|fverts| fverts.len() == 4));
+}
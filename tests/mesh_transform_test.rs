@@ -0,0 +1,22 @@
+use openmodel::geometry::{Mesh, Point};
+use openmodel::primitives::{Transformable, Xform};
+
+#[test]
+fn test_transform_keeps_vertex_normals_perpendicular_under_non_uniform_scaling() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(1.0, 0.0, 1.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    let s = 1.0 / 2.0_f32.sqrt();
+    for vk in [a, b, c] {
+        mesh.vertex.get_mut(&vk).unwrap().set_normal(s, s, 0.0);
+    }
+
+    let scaled = mesh.transformed(&Xform::scaling(3.0, 1.0, 1.0));
+    let n = scaled.vertex[&a].normal().unwrap();
+    let (pa, pb) = (scaled.vertex_position(a).unwrap(), scaled.vertex_position(b).unwrap());
+    let edge = [pb.x - pa.x, pb.y - pa.y, pb.z - pa.z];
+    assert!((n[0] * edge[0] + n[1] * edge[1] + n[2] * edge[2]).abs() < 1e-5);
+    assert!(((n[0] * n[0] + n[1] * n[1] + n[2] * n[2]) - 1.0).abs() < 1e-5);
+}
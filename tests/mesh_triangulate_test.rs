@@ -0,0 +1,44 @@
+use openmodel::geometry::{Mesh, Point};
+use std::collections::HashMap;
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_triangulate_cube() {
+    let mut cube = unit_cube();
+    cube.triangulate();
+    assert_eq!(cube.number_of_faces(), 12);
+    assert!(cube.face.values().all(|fverts| fverts.len() == 3));
+    assert_eq!(cube.euler(), 2);
+    assert!(cube.naked_vertices().is_empty());
+    assert!(cube.is_manifold());
+    assert!((cube.volume() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_triangulate_copies_face_attributes() {
+    let mut cube = unit_cube();
+    let top = *cube.face.keys().min().unwrap();
+    cube.facedata.insert(top, HashMap::from([("material".to_string(), 3.0)]));
+    cube.triangulate();
+    let tagged: Vec<&usize> = cube
+        .facedata
+        .iter()
+        .filter(|(_, attrs)| attrs.get("material") == Some(&3.0))
+        .map(|(fk, _)| fk)
+        .collect();
+    assert_eq!(tagged.len(), 2);
+    assert!(tagged.contains(&&top));
+}
+
+#[test]
+fn test_triangulate_keeps_triangles() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    let f = mesh.add_face(vec![a, b, c], None).unwrap();
+    mesh.triangulate();
+    assert_eq!(mesh.face_vertices(f), Some(&vec![a, b, c]));
+}
@@ -0,0 +1,39 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_cube_round_trip_through_flat_arrays() {
+    let cube = unit_cube();
+    let (vertices, faces) = cube.to_vertices_and_faces();
+    assert_eq!(vertices.len(), 8);
+    assert_eq!(faces.len(), 6);
+    assert!(faces.iter().flatten().all(|i| *i < vertices.len()));
+
+    let polygons: Vec<Vec<Point>> = faces.iter().map(|f| f.iter().map(|i| vertices[*i]).collect()).collect();
+    let rebuilt = Mesh::from_polygons(polygons, None);
+    assert_eq!(rebuilt.number_of_vertices(), cube.number_of_vertices());
+    assert_eq!(rebuilt.number_of_faces(), cube.number_of_faces());
+    assert_eq!(rebuilt.number_of_edges(), cube.number_of_edges());
+}
+
+#[test]
+fn test_sparse_keys_are_compacted_in_key_order() {
+    let mut mesh = Mesh::new();
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), Some(42));
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), Some(5));
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), Some(17));
+    mesh.add_face(vec![a, b, c], Some(3));
+    mesh.add_face(vec![c, b, a], Some(1));
+
+    let map = mesh.vertex_index_map();
+    assert_eq!((map[&a], map[&b], map[&c]), (0, 1, 2));
+
+    let (vertices, faces) = mesh.to_vertices_and_faces();
+    assert_eq!(vertices, vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)]);
+    // Faces ordered by face key
+    assert_eq!(faces, vec![vec![2, 1, 0], vec![0, 1, 2]]);
+    // Same output every call
+    assert_eq!(mesh.to_vertices_and_faces().1, faces);
+}
@@ -0,0 +1,76 @@
+use openmodel::geometry::{Mesh, Point};
+
+mod common;
+use common::{two_triangles, unit_cube_polygons};
+
+/// Polygon soup: every corner gets its own vertex.
+fn soup(polygons: &[Vec<Point>]) -> Mesh {
+    let mut mesh = Mesh::new();
+    for poly in polygons {
+        let keys: Vec<usize> = poly.iter().map(|p| mesh.add_vertex(*p, None)).collect();
+        mesh.add_face(keys, None);
+    }
+    mesh
+}
+
+fn assert_same_topology(a: &Mesh, b: &Mesh) {
+    assert_eq!(a.number_of_vertices(), b.number_of_vertices());
+    assert_eq!(a.number_of_faces(), b.number_of_faces());
+    assert_eq!(a.number_of_edges(), b.number_of_edges());
+    assert_eq!(a.to_vertices_and_faces().1, b.to_vertices_and_faces().1);
+}
+
+#[test]
+fn test_weld_grid_cube_matches_from_polygons() {
+    let mut mesh = soup(&unit_cube_polygons());
+    assert_eq!(mesh.number_of_vertices(), 24);
+    assert_eq!(mesh.weld_grid(1e-4), 16);
+    assert_same_topology(&mesh, &Mesh::from_polygons(unit_cube_polygons(), Some(1e-4)));
+    assert!(mesh.naked_vertices().is_empty());
+}
+
+#[test]
+fn test_weld_grid_exact_matches_from_polygons() {
+    let mut mesh = soup(&unit_cube_polygons());
+    mesh.weld_grid(0.0);
+    assert_same_topology(&mesh, &Mesh::from_polygons(unit_cube_polygons(), None));
+}
+
+#[test]
+fn test_weld_grid_precision_cases() {
+    for (offset, cell, expected) in [(0.3, 1.0, 4), (0.01, 1e-3, 6)] {
+        let mut mesh = soup(&two_triangles(offset));
+        mesh.weld_grid(cell);
+        let reference = Mesh::from_polygons(two_triangles(offset), Some(cell));
+        assert_eq!(mesh.number_of_vertices(), expected);
+        assert_same_topology(&mesh, &reference);
+    }
+}
+
+#[test]
+fn test_weld_grid_removes_collapsed_faces() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(0.001, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+    assert_eq!(mesh.weld_grid(0.1), 1);
+    assert_eq!(mesh.number_of_faces(), 0);
+    assert_eq!(mesh.number_of_vertices(), 2);
+}
+
+#[test]
+fn test_merge_then_weld_vertices_shares_edge() {
+    let mut mesh = soup(&[vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)]]);
+    let other = soup(&[vec![Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0)]]);
+    mesh.merge(&other);
+    assert_eq!(mesh.number_of_vertices(), 6);
+    assert_eq!(mesh.number_of_edges(), 6);
+
+    assert_eq!(mesh.weld_vertices(1e-4), 2);
+    assert_eq!(mesh.number_of_vertices(), 4);
+    assert_eq!(mesh.number_of_faces(), 2);
+    assert_eq!(mesh.number_of_edges(), 5);
+    let shared: Vec<(usize, usize)> = mesh.edges().into_iter().filter(|(u, v)| mesh.edge_faces(*u, *v).len() == 2).collect();
+    assert_eq!(shared.len(), 1);
+}
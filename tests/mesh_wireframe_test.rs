@@ -0,0 +1,28 @@
+use openmodel::geometry::Color;
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_cube_wireframe_has_twelve_colored_lines() {
+    let cube = unit_cube();
+    let color = Color::rgb(10, 200, 30);
+    let wire = cube.to_wireframe(color);
+
+    assert_eq!(wire.lines.len(), 12);
+    assert_eq!(wire.colors.len(), 12);
+    assert!(wire.colors.iter().all(|c| *c == color));
+    // Every cube edge has unit length
+    assert!(wire.lines.iter().all(|l| (l.length() - 1.0).abs() < 1e-6));
+}
+
+#[test]
+fn test_wireframe_is_deterministic() {
+    let cube = unit_cube();
+    let a = cube.to_wireframe(Color::black());
+    let b = cube.clone().to_wireframe(Color::black());
+    let coords = |w: &openmodel::geometry::LineCloud| -> Vec<[f32; 6]> {
+        w.lines.iter().map(|l| [l.x0, l.y0, l.z0, l.x1, l.y1, l.z1]).collect()
+    };
+    assert_eq!(coords(&a), coords(&b));
+}
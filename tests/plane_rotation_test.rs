@@ -0,0 +1,56 @@
+use openmodel::geometry::{Plane, Point, Vector};
+use std::f32::consts::FRAC_PI_2;
+
+fn close(a: &Vector, b: &Vector) -> bool {
+    (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6 && (a.z - b.z).abs() < 1e-6
+}
+
+#[test]
+fn test_rotate_about_normal_swaps_axes() {
+    let mut plane = Plane::default();
+    let (x, y) = (plane.xaxis, plane.yaxis);
+    let (a, b, c, d) = (plane.a, plane.b, plane.c, plane.d);
+
+    plane.rotate_about_normal(FRAC_PI_2);
+
+    assert!(close(&plane.xaxis, &y));
+    assert!(close(&plane.yaxis, &Vector::new(-x.x, -x.y, -x.z)));
+    assert!(close(&plane.zaxis, &Vector::new(0.0, 0.0, 1.0)));
+    assert_eq!((plane.a, plane.b, plane.c, plane.d), (a, b, c, d));
+}
+
+#[test]
+fn test_rotated_about_offset_axis() {
+    let plane = Plane::from_point_normal(&Point::new(1.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0));
+    // Quarter turn about the world Z axis moves the origin onto +Y
+    let rotated = plane.rotated(&Vector::new(0.0, 0.0, 2.0), FRAC_PI_2, &Point::new(0.0, 0.0, 0.0));
+
+    assert!((rotated.origin.x).abs() < 1e-6);
+    assert!((rotated.origin.y - 1.0).abs() < 1e-6);
+    assert!(close(&rotated.zaxis, &plane.zaxis));
+
+    // Tilting the plane updates the equation coefficients
+    let tilted = plane.rotated(&Vector::new(1.0, 0.0, 0.0), FRAC_PI_2, &Point::new(1.0, 0.0, 0.0));
+    assert!(close(&tilted.zaxis, &Vector::new(0.0, -1.0, 0.0)));
+    assert!((tilted.a).abs() < 1e-6 && (tilted.b + 1.0).abs() < 1e-6 && (tilted.c).abs() < 1e-6);
+    assert!((tilted.d).abs() < 1e-6);
+
+    // The source plane is unchanged
+    assert_eq!(plane.origin.x, 1.0);
+}
+
+#[test]
+fn test_plane_mirror_xform() {
+    let xy = Plane::default();
+    let p = xy.mirror_xform().transform_point(&Point::new(1.0, 2.0, 3.0));
+    assert!((p.x - 1.0).abs() < 1e-6 && (p.y - 2.0).abs() < 1e-6 && (p.z + 3.0).abs() < 1e-6);
+
+    let yz = Plane::from_point_normal(&Point::new(0.0, 0.0, 0.0), &Vector::new(1.0, 0.0, 0.0));
+    let p = yz.mirror_xform().transform_point(&Point::new(1.0, 2.0, 3.0));
+    assert!((p.x + 1.0).abs() < 1e-6 && (p.y - 2.0).abs() < 1e-6 && (p.z - 3.0).abs() < 1e-6);
+
+    // Points on an offset plane stay put
+    let offset = Plane::from_point_normal(&Point::new(0.0, 0.0, 4.0), &Vector::new(0.0, 1.0, 1.0));
+    let on = offset.mirror_xform().transform_point(&Point::new(5.0, 1.0, 3.0));
+    assert!((on.x - 5.0).abs() < 1e-5 && (on.y - 1.0).abs() < 1e-5 && (on.z - 3.0).abs() < 1e-5);
+}
@@ -0,0 +1,29 @@
+use openmodel::geometry::{Point, Pline};
+
+#[test]
+fn test_to_arrows_follows_consecutive_points() {
+    let points = vec![
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+        Point::new(1.0, 2.0, 0.0),
+        Point::new(1.0, 2.0, 3.0),
+    ];
+    let mut pline = Pline::new(points.clone());
+    pline.data.set_color([255, 0, 0]);
+    pline.data.set_thickness(0.25);
+
+    let arrows = pline.to_arrows();
+    assert_eq!(arrows.len(), points.len() - 1);
+    for (i, arrow) in arrows.iter().enumerate() {
+        assert_eq!((arrow.x0, arrow.y0, arrow.z0), (points[i].x, points[i].y, points[i].z));
+        assert_eq!((arrow.x1, arrow.y1, arrow.z1), (points[i + 1].x, points[i + 1].y, points[i + 1].z));
+        assert_eq!(arrow.data.get_color(), [255, 0, 0]);
+        assert_eq!(arrow.data.get_thickness(), 0.25);
+    }
+}
+
+#[test]
+fn test_to_arrows_single_point_is_empty() {
+    let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0)]);
+    assert!(pline.to_arrows().is_empty());
+}
@@ -0,0 +1,28 @@
+use openmodel::geometry::{Color, Point, Pline};
+
+#[test]
+fn test_color_at_arc_length_midpoint_is_purple() {
+    let mut pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0)]);
+    pline.colors = vec![Color::red(), Color::blue()];
+
+    let mid = pline.color_at_arc_length(pline.length() * 0.5);
+    assert_eq!(mid, Color::rgb(128, 0, 128));
+    assert_eq!(pline.color_at_arc_length(0.0), Color::red());
+    assert_eq!(pline.color_at_arc_length(100.0), Color::blue());
+}
+
+#[test]
+fn test_color_at_arc_length_without_colors_uses_data_color() {
+    let mut pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+    pline.data.set_color([10, 20, 30]);
+    assert_eq!(pline.color_at_arc_length(0.5), Color::rgb(10, 20, 30));
+}
+
+#[test]
+fn test_pline_without_colors_deserializes() {
+    let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+    let mut value = serde_json::to_value(&pline).unwrap();
+    value.as_object_mut().unwrap().remove("colors");
+    let restored: Pline = serde_json::from_value(value).unwrap();
+    assert!(restored.colors.is_empty());
+}
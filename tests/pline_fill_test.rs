@@ -0,0 +1,61 @@
+use openmodel::geometry::{Pline, Point};
+
+fn filled_area(pline: &Pline) -> f32 {
+    let mesh = pline.to_filled_mesh().expect("fillable polyline");
+    mesh.face.keys().map(|&f| mesh.face_area(f).unwrap()).sum()
+}
+
+#[test]
+fn test_square_fills_to_two_triangles() {
+    let square = Pline::new(vec![
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(2.0, 0.0, 0.0),
+        Point::new(2.0, 2.0, 0.0),
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, 0.0, 0.0),
+    ]);
+    let mesh = square.to_filled_mesh().unwrap();
+    assert_eq!(mesh.number_of_vertices(), 4);
+    assert_eq!(mesh.number_of_faces(), 2);
+    assert!((filled_area(&square) - 4.0).abs() < 1e-5);
+    for f in mesh.face.keys() {
+        assert!(mesh.face_normal(*f).unwrap().z > 0.0);
+    }
+}
+
+#[test]
+fn test_concave_tilted_polygon() {
+    // L-shape of area 3 in the plane x = z
+    let l_shape: Vec<Point> = [[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [1.0, 1.0], [1.0, 2.0], [0.0, 2.0], [0.0, 0.0]]
+        .iter()
+        .map(|[u, v]| Point::new(*u, *v, *u))
+        .collect();
+    let pline = Pline::new(l_shape);
+    let mesh = pline.to_filled_mesh().unwrap();
+    assert_eq!(mesh.number_of_faces(), 4);
+    assert!((filled_area(&pline) - 3.0 * 2.0_f32.sqrt()).abs() < 1e-4);
+}
+
+#[test]
+fn test_invalid_polylines_are_none() {
+    let open = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0)]);
+    assert!(open.to_filled_mesh().is_none());
+
+    let bowtie = Pline::new(vec![
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(1.0, 1.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(0.0, 0.0, 0.0),
+    ]);
+    assert!(bowtie.to_filled_mesh().is_none());
+
+    let warped = Pline::new(vec![
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+        Point::new(1.0, 1.0, 0.5),
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(0.0, 0.0, 0.0),
+    ]);
+    assert!(warped.to_filled_mesh().is_none());
+}
@@ -0,0 +1,51 @@
+use openmodel::geometry::{Color, Pline, Point};
+
+#[test]
+fn test_split_straight_pline_at_arc_length() {
+    let mut pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0)]);
+    pline.data.set_name("path");
+    let (a, b) = pline.split_at_arc_length(4.0);
+
+    assert!((a.length() - 4.0).abs() < 1e-6);
+    assert!((b.length() - 6.0).abs() < 1e-6);
+    assert_eq!(a.points.len(), 2);
+    assert_eq!(b.points.len(), 3);
+    assert_eq!(a.points.last(), b.points.first());
+    assert_eq!(a.data.name(), "path");
+    assert_eq!(b.data.name(), "path");
+    assert_eq!(a.plane.zaxis.z, pline.plane.zaxis.z);
+}
+
+#[test]
+fn test_split_at_existing_vertex_and_ends() {
+    let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0)]);
+    let (a, b) = pline.split_at_arc_length(5.0);
+    assert_eq!(a.points.len(), 2);
+    assert_eq!(b.points.len(), 2);
+
+    let (a, b) = pline.split_at_arc_length(-1.0);
+    assert_eq!(a.points.len(), 1);
+    assert_eq!(b.length(), 10.0);
+
+    let (a, b) = pline.split_at_arc_length(20.0);
+    assert_eq!(a.length(), 10.0);
+    assert_eq!(b.points.len(), 1);
+}
+
+#[test]
+fn test_split_interpolates_colors() {
+    let mut pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0)]);
+    pline.colors = vec![Color::red(), Color::blue()];
+    let (a, b) = pline.split_at_arc_length(5.0);
+    let mid = Color::red().lerp(&Color::blue(), 0.5);
+    assert_eq!(a.colors, vec![Color::red(), mid]);
+    assert_eq!(b.colors, vec![mid, Color::blue()]);
+}
+
+#[test]
+fn test_split_at_closest_point() {
+    let pline = Pline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0)]);
+    let (a, b) = pline.split_at_closest_point(&Point::new(3.0, 2.0, 0.0));
+    assert!((a.length() - 3.0).abs() < 1e-6);
+    assert!((b.length() - 7.0).abs() < 1e-6);
+}
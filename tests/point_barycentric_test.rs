@@ -0,0 +1,68 @@
+use openmodel::primitives::Point;
+
+fn triangle() -> (Point, Point, Point) {
+    (Point::new(0.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0), Point::new(0.0, 3.0, 1.0))
+}
+
+#[test]
+fn test_barycentric_centroid() {
+    let (a, b, c) = triangle();
+    let centroid = Point::new((a.x + b.x + c.x) / 3.0, (a.y + b.y + c.y) / 3.0, (a.z + b.z + c.z) / 3.0);
+    let (u, v, w) = Point::barycentric(&centroid, &a, &b, &c);
+    for weight in [u, v, w] {
+        assert!((weight - 1.0 / 3.0).abs() < 1e-5, "weight {}", weight);
+    }
+    assert!(Point::in_triangle(&centroid, &a, &b, &c));
+}
+
+#[test]
+fn test_barycentric_outside_point() {
+    let (a, b, c) = triangle();
+    let outside = Point::new(-1.0, 1.0, 0.0);
+    let (u, v, w) = Point::barycentric(&outside, &a, &b, &c);
+    assert!(u < 0.0 || v < 0.0 || w < 0.0);
+    assert!((u + v + w - 1.0).abs() < 1e-5);
+    assert!(!Point::in_triangle(&outside, &a, &b, &c));
+}
+
+#[test]
+fn test_barycentric_degenerate_triangle() {
+    let a = Point::new(0.0, 0.0, 0.0);
+    let b = Point::new(1.0, 1.0, 1.0);
+    let c = Point::new(2.0, 2.0, 2.0);
+    let p = Point::new(1.0, 1.0, 1.0);
+    assert_eq!(Point::barycentric(&p, &a, &b, &c), (0.0, 0.0, 0.0));
+    assert!(!Point::in_triangle(&p, &a, &b, &c));
+}
+
+#[test]
+fn test_point_lerp() {
+    let a = Point::new(-1.0, 0.0, 4.0);
+    let b = Point::new(3.0, 2.0, 0.0);
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_eq!(a.lerp(&b, 1.0), b);
+    assert_eq!(a.lerp(&b, 0.5), Point::new(1.0, 1.0, 2.0));
+    assert_eq!(a.lerp(&b, 2.0), Point::new(7.0, 4.0, -4.0));
+}
+
+#[test]
+fn test_point_centroid() {
+    let square = [
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+        Point::new(1.0, 1.0, 0.0),
+        Point::new(0.0, 1.0, 0.0),
+    ];
+    assert_eq!(Point::centroid(&square), Some(Point::new(0.5, 0.5, 0.0)));
+    assert_eq!(Point::centroid(&square[..1]), Some(Point::new(0.0, 0.0, 0.0)));
+    assert_eq!(Point::centroid(&[]), None);
+}
+
+#[test]
+fn test_point_midpoint() {
+    let a = Point::new(0.0, 0.0, 0.0);
+    let b = Point::new(2.0, 4.0, 6.0);
+    assert_eq!(a.midpoint(&b), Point::new(1.0, 2.0, 3.0));
+    assert_eq!(b.midpoint(&a), a.lerp(&b, 0.5));
+    assert_eq!(a.midpoint(&a), a);
+}
@@ -0,0 +1,27 @@
+use openmodel::geometry::{Mesh, Point as GeometryPoint};
+use openmodel::primitives::{Point, Xform};
+
+#[test]
+fn test_geometry_and_primitives_share_one_point_type() {
+    // Both paths name the same type, so values move freely between them
+    let p: GeometryPoint = Point::new(1.0, 2.0, 3.0);
+    let q: Point = p;
+    assert_eq!(q, GeometryPoint::new(1.0, 2.0, 3.0));
+    assert_eq!(std::any::type_name::<Point>(), std::any::type_name::<GeometryPoint>());
+}
+
+#[test]
+fn test_xform_transform_point_returns_the_shared_point() {
+    let xform = Xform::translation(1.0, -1.0, 0.5) * Xform::scaling(2.0, 2.0, 2.0);
+    let p: GeometryPoint = xform.transform_point(&Point::new(1.0, 1.0, 1.0));
+    assert_eq!(p, Point::new(3.0, 1.0, 2.5));
+}
+
+#[test]
+fn test_mesh_add_vertex_accepts_the_shared_point() {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(GeometryPoint::new(1.0, 0.0, 0.0), None);
+    assert_eq!(mesh.vertex_position(a), Some(Point::origin()));
+    assert_eq!(mesh.vertex_position(b), Some(Point::new(1.0, 0.0, 0.0)));
+}
@@ -0,0 +1,77 @@
+use openmodel::common::{FromJsonData, HasJsonData};
+use openmodel::geometry::{Color, Point, PointCloud, Transformable, Vector, Xform};
+
+fn sample_cloud() -> PointCloud {
+    let mut cloud = PointCloud::new(
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 3.0)],
+        vec![Vector::new(0.0, 0.0, 1.0), Vector::new(1.0, 0.0, 0.0)],
+        vec![Color::red(), Color::blue()],
+    );
+    cloud.xform = Xform::translation(5.0, 0.0, -1.0);
+    cloud
+}
+
+#[test]
+fn test_pointcloud_serde_round_trip_keeps_normals_and_xform() {
+    let cloud = sample_cloud();
+    let value = serde_json::to_value(&cloud).unwrap();
+    let restored = PointCloud::from_json_data(&value).expect("point cloud");
+
+    assert_eq!(restored.points, cloud.points);
+    assert_eq!(restored.colors, cloud.colors);
+    assert_eq!(restored.normals.len(), 2);
+    assert_eq!(restored.normals[1].x, 1.0);
+    assert_eq!(restored.xform.m, cloud.xform.m);
+}
+
+#[test]
+fn test_pointcloud_json_data_includes_normals_and_xform() {
+    let cloud = sample_cloud();
+    let json = cloud.to_json_data(false);
+    assert_eq!(json["dtype"], "openmodel.geometry/PointCloud");
+    assert_eq!(json["data"]["normals"].as_array().map(|n| n.len()), Some(2));
+    assert!(json["data"].get("xform").is_some());
+}
+
+#[test]
+fn test_bounding_sphere_of_unit_sphere_samples() {
+    // Fibonacci sphere samples on the unit sphere
+    let n = 200;
+    let golden = std::f32::consts::PI * (3.0 - 5.0_f32.sqrt());
+    let points: Vec<Point> = (0..n)
+        .map(|i| {
+            let z = 1.0 - 2.0 * (i as f32 + 0.5) / n as f32;
+            let r = (1.0 - z * z).sqrt();
+            let theta = golden * i as f32;
+            Point::new(r * theta.cos(), r * theta.sin(), z)
+        })
+        .collect();
+    let cloud = PointCloud::new(points, vec![], vec![]);
+
+    let (center, radius) = cloud.bounding_sphere().unwrap();
+    assert!((radius - 1.0).abs() < 0.05, "radius {}", radius);
+    assert!(center.distance(&Point::new(0.0, 0.0, 0.0)) < 0.05);
+    for p in &cloud.points {
+        assert!(center.distance(p) <= radius + 1e-5);
+    }
+    assert!(cloud.density() > 0.0);
+}
+
+#[test]
+fn test_empty_cloud_statistics() {
+    let cloud = PointCloud::new(vec![], vec![], vec![]);
+    assert!(cloud.bounding_sphere().is_none());
+    assert_eq!(cloud.density(), 0.0);
+}
+
+#[test]
+fn test_transform_applies_normal_matrix_under_non_uniform_scaling() {
+    let s = 1.0 / 2.0_f32.sqrt();
+    let mut cloud = PointCloud::new(vec![Point::new(1.0, 1.0, 0.0)], vec![Vector::new(s, s, 0.0)], vec![Color::red()]);
+    cloud.transform(&Xform::scaling(2.0, 1.0, 1.0));
+
+    let expected = Vector::new(0.5, 1.0, 0.0).unitized();
+    let n = cloud.normals[0];
+    assert!((n.x - expected.x).abs() < 1e-6 && (n.y - expected.y).abs() < 1e-6 && n.z.abs() < 1e-6);
+    assert_eq!((cloud.points[0].x, cloud.points[0].y), (2.0, 1.0));
+}
@@ -0,0 +1,17 @@
+use openmodel::{geometry, primitives};
+
+#[test]
+fn test_point_display_matches_across_modules() {
+    let p = primitives::Point::new(1.5, -2.0, 3.25);
+    let g = geometry::Point::new(1.5, -2.0, 3.25);
+    assert_eq!(format!("{}", p), "Point(1.5, -2, 3.25)");
+    assert_eq!(format!("{}", p), format!("{}", g));
+}
+
+#[test]
+fn test_vector_display_and_serialize_match_across_modules() {
+    let v = primitives::Vector::new(1.0, 0.0, -1.0);
+    let g = geometry::Vector::new(1.0, 0.0, -1.0);
+    assert_eq!(format!("{}", v), format!("{}", g));
+    assert_eq!(serde_json::to_value(v).unwrap(), serde_json::to_value(g).unwrap());
+}
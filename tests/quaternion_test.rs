@@ -0,0 +1,97 @@
+use openmodel::primitives::{Point, Quaternion, Vector, Xform};
+use std::f32::consts::PI;
+
+fn assert_point_close(a: &Point, b: &Point) {
+    assert!(
+        (a.x - b.x).abs() < 1e-5 && (a.y - b.y).abs() < 1e-5 && (a.z - b.z).abs() < 1e-5,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+fn same_rotation(a: &Quaternion, b: &Quaternion) -> bool {
+    (a.normalize().dot(&b.normalize()).abs() - 1.0).abs() < 1e-5
+}
+
+#[test]
+fn test_to_xform_quarter_turn_about_z() {
+    let q = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), PI / 2.0);
+    let p = q.to_xform().transform_point(&Point::new(1.0, 0.0, 0.0));
+    assert_point_close(&p, &Point::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_from_axis_angle_normalizes_axis() {
+    let q = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 5.0), PI / 2.0);
+    assert!((q.magnitude() - 1.0).abs() < 1e-6);
+    assert!(Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 0.0), 1.0) == Quaternion::identity());
+}
+
+#[test]
+fn test_to_xform_matches_xform_rotation() {
+    let axis = Vector::new(1.0, 2.0, -0.5).normalize();
+    let q = Quaternion::from_axis_angle(axis, 0.8);
+    assert!(q.to_xform().is_close(&Xform::rotation(&axis, 0.8), 1e-5));
+    assert!(q.to_xform().is_rigid(1e-5));
+}
+
+#[test]
+fn test_slerp_endpoints() {
+    let a = Quaternion::from_axis_angle(Vector::new(1.0, 0.0, 0.0), 0.3);
+    let b = Quaternion::from_axis_angle(Vector::new(0.0, 1.0, 1.0), 1.7);
+    assert!(same_rotation(&a.slerp(&b, 0.0), &a));
+    assert!(same_rotation(&a.slerp(&b, 1.0), &b));
+}
+
+#[test]
+fn test_slerp_halfway_to_half_turn() {
+    let start = Quaternion::identity();
+    let end = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), PI);
+    let half = start.slerp(&end, 0.5);
+    // Both arcs to a half turn are equally short, so the axis may come out as +Z or -Z
+    let (axis, angle) = half.to_axis_angle();
+    assert!((angle - PI / 2.0).abs() < 1e-5);
+    assert!((axis.z.abs() - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_slerp_takes_shortest_arc_and_normalizes() {
+    let a = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), 0.2);
+    let b = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), 0.6);
+    // -b is the same rotation; a scaled endpoint must not change the result
+    let result = (a * 3.0).slerp(&-b, 0.5);
+    let expected = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), 0.4);
+    assert!((result.magnitude() - 1.0).abs() < 1e-5);
+    assert!(result.to_xform().is_close(&expected.to_xform(), 1e-5));
+}
+
+#[test]
+fn test_product_of_two_eighth_turns_is_quarter_turn() {
+    let eighth = Quaternion::from_axis_angle(Vector::new(0.0, 0.0, 1.0), PI / 4.0);
+    let composed = eighth * eighth;
+    assert!(composed.to_xform().is_close(&Xform::rotation_z(PI / 2.0), 1e-5));
+    let p = composed.to_xform().transform_point(&Point::new(1.0, 0.0, 0.0));
+    assert_point_close(&p, &Point::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+#[allow(clippy::op_ref)] // the borrowed impl must agree with the by-value one
+fn test_product_order_matches_xform_composition() {
+    let a = Quaternion::from_axis_angle(Vector::new(1.0, 0.0, 0.0), 0.4);
+    let b = Quaternion::from_axis_angle(Vector::new(0.0, 1.0, 0.0), 1.1);
+    assert!((a * b).to_xform().is_close(&(a.to_xform() * b.to_xform()), 1e-5));
+    assert!((a * b).to_xform().is_close(&(&a * &b).to_xform(), 0.0));
+}
+
+#[test]
+fn test_normalize_mut() {
+    let mut q = Quaternion::new(1.0, 1.0, 1.0, 1.0);
+    assert!(q.normalize_mut());
+    assert!((q.magnitude() - 1.0).abs() < 1e-6);
+    assert!((q.s - 0.5).abs() < 1e-6);
+
+    let mut zero = Quaternion::zero();
+    assert!(!zero.normalize_mut());
+    assert_eq!(zero.s, 0.0);
+}
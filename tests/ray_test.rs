@@ -0,0 +1,40 @@
+use openmodel::geometry::{Plane, Point, Ray, Vector};
+use openmodel::primitives::{Transformable, Xform};
+
+mod common;
+use common::unit_cube;
+
+#[test]
+fn test_ray_hits_xy_plane() {
+    let ray = Ray::new(Point::new(2.0, -3.0, 4.0), Vector::new(0.0, 0.0, -1.0));
+    assert_eq!(ray.intersect_plane(&Plane::default()), Some(Point::new(2.0, -3.0, 0.0)));
+
+    // Pointing away or parallel
+    let up = Ray::new(Point::new(0.0, 0.0, 4.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(up.intersect_plane(&Plane::default()).is_none());
+    let flat = Ray::new(Point::new(0.0, 0.0, 4.0), Vector::new(1.0, 0.0, 0.0));
+    assert!(flat.intersect_plane(&Plane::default()).is_none());
+}
+
+#[test]
+fn test_ray_hits_nearest_box_face() {
+    let mesh = unit_cube();
+    let ray = Ray::new(Point::new(0.25, 0.75, 5.0), Vector::new(0.0, 0.0, -2.0));
+    let (hit, face) = ray.intersect_mesh(&mesh).expect("ray crosses the box");
+
+    assert!((hit.z - 1.0).abs() < 1e-6);
+    assert!((hit.x - 0.25).abs() < 1e-6 && (hit.y - 0.75).abs() < 1e-6);
+    assert!(mesh.face[&face].iter().all(|v| mesh.vertex[v].z == 1.0));
+
+    let miss = Ray::new(Point::new(2.0, 2.0, 5.0), Vector::new(0.0, 0.0, -1.0));
+    assert!(miss.intersect_mesh(&mesh).is_none());
+}
+
+#[test]
+fn test_ray_transform() {
+    let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+    let moved = ray.transformed(&Xform::translation(0.0, 0.0, 3.0));
+    assert_eq!(moved.origin, Point::new(0.0, 0.0, 3.0));
+    assert_eq!(moved.direction, Vector::new(1.0, 0.0, 0.0));
+    assert_eq!(moved.at(2.0), Point::new(2.0, 0.0, 3.0));
+}
@@ -0,0 +1,36 @@
+use openmodel::common::{Data, GeometryRegistry};
+use openmodel::geometry::Line;
+
+#[test]
+fn test_lookup_by_guid_and_name() {
+    let mut line = Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+    line.data.set_name("edge");
+    let point_data = Data::with_name("corner");
+    let other = Data::with_name("edge");
+
+    let mut registry = GeometryRegistry::new();
+    let h_line = registry.register_data(&line.data);
+    let h_point = registry.register(point_data.guid(), point_data.name());
+    let h_other = registry.register_data(&other);
+
+    assert_eq!(registry.len(), 3);
+    assert_eq!(registry.by_guid(&line.data.guid()), Some(h_line));
+    assert_eq!(registry.by_guid(&point_data.guid()), Some(h_point));
+    assert_eq!(registry.by_name("corner"), &[h_point]);
+    assert_eq!(registry.by_name("edge"), &[h_line, h_other]);
+    assert_eq!(registry.get(h_point), Some((point_data.guid(), "corner")));
+    assert!(registry.by_name("missing").is_empty());
+    assert!(registry.by_guid(&Data::new().guid()).is_none());
+}
+
+#[test]
+fn test_reregister_updates_name() {
+    let data = Data::with_name("a");
+    let mut registry = GeometryRegistry::new();
+    let handle = registry.register_data(&data);
+
+    assert_eq!(registry.register(data.guid(), "b"), handle);
+    assert_eq!(registry.len(), 1);
+    assert!(registry.by_name("a").is_empty());
+    assert_eq!(registry.by_name("b"), &[handle]);
+}
@@ -0,0 +1,51 @@
+use openmodel::geometry::{Line, Mesh, Point, Vector};
+use openmodel::primitives::Xform;
+use openmodel::{AllGeometryData, MeshInstances};
+
+fn scene() -> AllGeometryData {
+    let mut mesh = Mesh::new();
+    let a = mesh.add_vertex(Point::new(0.0, 0.0, 0.0), None);
+    let b = mesh.add_vertex(Point::new(1.0, 0.0, 0.0), None);
+    let c = mesh.add_vertex(Point::new(0.0, 1.0, 0.0), None);
+    mesh.add_face(vec![a, b, c], None);
+
+    AllGeometryData {
+        points: vec![Point::new(1.0, 2.0, 3.0)],
+        vectors: vec![Vector::new(0.0, 0.0, 1.0)],
+        lines: vec![Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0)],
+        arrows: vec![],
+        planes: vec![],
+        colors: vec![],
+        point_clouds: vec![],
+        line_clouds: vec![],
+        plines: vec![],
+        xforms: vec![],
+        meshes: vec![mesh, Mesh::create_unit_pipe_low_res()],
+        mesh_instances: vec![MeshInstances { mesh_index: 1, transforms: vec![Xform::translation(5.0, 0.0, 0.0)] }],
+        pipe_mesh_index: None,
+        sphere_mesh_index: None,
+    }
+}
+
+#[test]
+fn test_translate_scene_moves_points_and_meshes_equally() {
+    let mut data = scene();
+    let before_point = data.points[0];
+    let vkey = *data.meshes[0].vertex.keys().next().unwrap();
+    let before_vertex = data.meshes[0].vertex_position(vkey).unwrap();
+
+    data.transform(&Xform::translation(1.0, -2.0, 0.5));
+
+    let dp = data.points[0] - &before_point;
+    let dv = data.meshes[0].vertex_position(vkey).unwrap() - &before_vertex;
+    assert_eq!(dp, Point::new(1.0, -2.0, 0.5));
+    assert_eq!(dp, dv);
+
+    // Directions ignore translation
+    assert_eq!(data.vectors[0], Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(data.lines[0].x1, 2.0);
+
+    // Instance transforms are pre-multiplied, the template mesh is left alone
+    let t = &data.mesh_instances[0].transforms[0];
+    assert_eq!((t.m[12], t.m[13], t.m[14]), (6.0, -2.0, 0.5));
+}
@@ -0,0 +1,155 @@
+use openmodel::primitives::Vector;
+
+#[test]
+fn test_scalar_ops_on_borrowed_vector() {
+    let v = Vector::new(1.0, -2.0, 3.0);
+    let doubled = Vector::new(2.0, -4.0, 6.0);
+
+    assert_eq!(&v * 2.0, doubled);
+    assert_eq!(2.0 * &v, doubled);
+    assert_eq!(2.0 * v, doubled);
+    assert_eq!(&v / 0.5, doubled);
+
+    // v is still usable and unchanged
+    assert_eq!(v, Vector::new(1.0, -2.0, 3.0));
+}
+
+#[test]
+fn test_zero_and_one_constructors() {
+    use openmodel::primitives::{Point, Xform};
+
+    assert_eq!(Vector::zero().length(), 0.0);
+    assert_eq!(Vector::one(), Vector::new(1.0, 1.0, 1.0));
+    assert_eq!(Point::origin(), Point::default());
+    assert_eq!(Xform::IDENTITY.m, Xform::identity().m);
+}
+
+#[test]
+fn test_spherical_unit_z() {
+    let (r, theta, phi) = Vector::unit_z().to_spherical();
+    assert_eq!((r, theta, phi), (1.0, 0.0, 0.0));
+    let v = Vector::from_spherical(r, theta, phi);
+    assert!((v.x - 0.0).abs() < 1e-12 && (v.y - 0.0).abs() < 1e-12 && (v.z - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_spherical_and_cylindrical_roundtrip() {
+    let v = Vector::new(-1.5, 2.0, -0.5);
+    let (r, theta, phi) = v.to_spherical();
+    let s = Vector::from_spherical(r, theta, phi);
+    assert!((s - v).length() < 1e-5);
+
+    let (rho, phi, z) = v.to_cylindrical();
+    assert!((rho - 2.5).abs() < 1e-6);
+    let c = Vector::from_cylindrical(rho, phi, z);
+    assert!((c - v).length() < 1e-5);
+}
+
+#[test]
+fn test_unitized_returns_new_unit_vector() {
+    let v = Vector::new(0.0, 3.0, 4.0);
+    let u = v.unitized();
+    assert!((u.x - 0.0).abs() < 1e-6 && (u.y - 0.6).abs() < 1e-6 && (u.z - 0.8).abs() < 1e-6);
+    assert!((u.length() - 1.0).abs() < 1e-6);
+    assert_eq!(v.length(), 5.0);
+
+    let n = v.normalize();
+    assert!((n.x - u.x).abs() < 1e-6 && (n.y - u.y).abs() < 1e-6 && (n.z - u.z).abs() < 1e-6);
+}
+
+#[test]
+fn test_unitized_zero_vector_is_zero() {
+    let zero = Vector::new(0.0, 0.0, 0.0).unitized();
+    assert_eq!((zero.x, zero.y, zero.z), (0.0, 0.0, 0.0));
+    let tiny = Vector::new(1e-7, 0.0, 0.0).unitized();
+    assert_eq!(tiny.length(), 0.0);
+}
+
+#[test]
+#[allow(clippy::op_ref)] // exercises the reference operator impls on purpose
+fn test_add_sub_neg() {
+    let a = Vector::new(1.0, 2.0, 3.0);
+    let b = Vector::new(4.0, 5.0, 6.0);
+
+    for sum in [a + b, &a + &b, a + &b] {
+        assert_eq!((sum.x, sum.y, sum.z), (5.0, 7.0, 9.0));
+    }
+    for diff in [b - a, &b - &a, b - &a] {
+        assert_eq!((diff.x, diff.y, diff.z), (3.0, 3.0, 3.0));
+    }
+    for neg in [-a, -&a] {
+        assert_eq!((neg.x, neg.y, neg.z), (-1.0, -2.0, -3.0));
+    }
+}
+
+#[test]
+fn test_angle() {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    let x = Vector::new(1.0, 0.0, 0.0);
+    let y = Vector::new(0.0, 1.0, 0.0);
+    assert!((x.angle(&y) - FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle(&Vector::new(3.0, 3.0, 0.0)) - FRAC_PI_4).abs() < 1e-6);
+    assert!((x.angle(&Vector::new(-2.0, 0.0, 0.0)) - PI).abs() < 1e-6);
+    assert_eq!(x.angle(&Vector::new(5.0, 0.0, 0.0)), 0.0);
+    assert_eq!(x.angle(&Vector::new(0.0, 0.0, 0.0)), 0.0);
+}
+
+#[test]
+fn test_angle_signed_flips_with_normal() {
+    use std::f32::consts::FRAC_PI_2;
+    let x = Vector::new(1.0, 0.0, 0.0);
+    let y = Vector::new(0.0, 1.0, 0.0);
+    let up = Vector::new(0.0, 0.0, 1.0);
+    let down = Vector::new(0.0, 0.0, -1.0);
+    assert!((x.angle_signed(&y, &up) - FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle_signed(&y, &down) + FRAC_PI_2).abs() < 1e-6);
+    assert!((x.angle_signed(&y, &up) + y.angle_signed(&x, &up)).abs() < 1e-6);
+}
+
+#[test]
+fn test_reflect() {
+    let r = Vector::new(1.0, -1.0, 0.0).reflect(&Vector::new(0.0, 1.0, 0.0));
+    assert!((r.x - 1.0).abs() < 1e-6 && (r.y - 1.0).abs() < 1e-6 && r.z.abs() < 1e-6);
+
+    // Reflection keeps the length and is its own inverse
+    let v = Vector::new(2.0, -3.0, 0.5);
+    let n = Vector::new(1.0, 1.0, 1.0);
+    let once = v.reflect(&n);
+    let twice = once.reflect(&n);
+    assert!((once.length() - v.length()).abs() < 1e-5);
+    assert!((twice.x - v.x).abs() < 1e-5 && (twice.y - v.y).abs() < 1e-5 && (twice.z - v.z).abs() < 1e-5);
+
+    let same = v.reflect(&Vector::new(0.0, 0.0, 0.0));
+    assert_eq!((same.x, same.y, same.z), (v.x, v.y, v.z));
+}
+
+#[test]
+fn test_vector_lerp() {
+    let a = Vector::new(1.0, 2.0, 3.0);
+    let b = Vector::new(5.0, -2.0, 7.0);
+    let start = a.lerp(&b, 0.0);
+    let end = a.lerp(&b, 1.0);
+    let mid = a.lerp(&b, 0.5);
+    assert_eq!((start.x, start.y, start.z), (1.0, 2.0, 3.0));
+    assert_eq!((end.x, end.y, end.z), (5.0, -2.0, 7.0));
+    assert_eq!((mid.x, mid.y, mid.z), (3.0, 0.0, 5.0));
+}
+
+#[test]
+fn test_rotate_around_axis() {
+    use openmodel::primitives::{Point, Xform};
+    use std::f32::consts::FRAC_PI_2;
+    let x = Vector::new(1.0, 0.0, 0.0);
+    let r = x.rotate_around_axis(&Vector::new(0.0, 0.0, 3.0), FRAC_PI_2);
+    assert!(r.x.abs() < 1e-6 && (r.y - 1.0).abs() < 1e-6 && r.z.abs() < 1e-6);
+
+    // Matches the equivalent Xform rotation
+    let v = Vector::new(0.3, -1.2, 2.0);
+    let axis = Vector::new(1.0, 1.0, -0.5);
+    let rotated = v.rotate_around_axis(&axis, 0.9);
+    let expected = Xform::rotation(&axis, 0.9).transform_point(&Point::new(v.x, v.y, v.z));
+    assert!((rotated.x - expected.x).abs() < 1e-5 && (rotated.y - expected.y).abs() < 1e-5 && (rotated.z - expected.z).abs() < 1e-5);
+
+    let same = v.rotate_around_axis(&Vector::new(0.0, 0.0, 0.0), 0.9);
+    assert_eq!((same.x, same.y, same.z), (v.x, v.y, v.z));
+}
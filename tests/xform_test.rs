@@ -0,0 +1,237 @@
+use openmodel::primitives::{Point, Quaternion, Vector, Xform};
+
+#[test]
+fn test_is_identity_with_tolerance() {
+    assert!(Xform::identity().is_identity(0.0));
+    let mut almost = Xform::identity();
+    almost.m[12] = 1e-7;
+    assert!(almost.is_identity(1e-6));
+    assert!(!almost.is_identity(0.0));
+}
+
+#[test]
+fn test_is_rigid() {
+    assert!(Xform::identity().is_rigid(1e-6));
+    let axis = Vector::new(1.0, 1.0, 0.0).normalize();
+    assert!(Xform::rotation(&axis, 1.2).is_rigid(1e-5));
+    assert!((Xform::translation(3.0, -1.0, 2.0) * Xform::rotation_x(0.7)).is_rigid(1e-5));
+
+    assert!(!Xform::scaling(2.0, 2.0, 2.0).is_rigid(1e-5));
+    // Mirror is orthonormal but has determinant -1
+    assert!(!Xform::scaling(-1.0, 1.0, 1.0).is_rigid(1e-5));
+}
+
+#[test]
+fn test_display_prints_all_sixteen_elements() {
+    let mut xform = Xform::identity();
+    for (i, v) in xform.m.iter_mut().enumerate() {
+        *v = i as f32;
+    }
+    let text = xform.to_string();
+    let values: Vec<f32> = text
+        .lines()
+        .skip(1)
+        .flat_map(|row| row.trim_matches(|c| c == '[' || c == ']').split(", ").map(|v| v.parse::<f32>().unwrap()))
+        .collect();
+    assert_eq!(values.len(), 16);
+    // Rows are printed in reading order from the column-major storage
+    for row in 0..4 {
+        for col in 0..4 {
+            assert_eq!(values[row * 4 + col], xform.m[col * 4 + row]);
+        }
+    }
+}
+
+#[test]
+fn test_composed_xform_json_round_trip() {
+    use openmodel::common::{Data, FromJsonData, HasJsonData};
+
+    let x = Xform::translation(1.0, -2.0, 3.0);
+    let y = Xform::rotation_z(0.4);
+    let composed = &x * &y;
+    assert!(!composed.is_close(&(&y * &x), 1e-6));
+
+    let json = composed.to_json_data(false);
+    assert_eq!(json["dtype"], "openmodel.primitives/Xform");
+    let restored = Xform::from_json_data(&json).expect("xform json");
+    assert!(restored.is_close(&composed, 1e-6));
+
+    // Stored on a geometry's data and read back
+    let mut data = Data::with_name("part");
+    data.set_xform(&restored);
+    assert!(data.xform().is_close(&composed, 1e-6));
+}
+
+#[test]
+fn test_inverse_of_translation_scaling_composite() {
+    let m = Xform::translation(3.0, -2.0, 5.0) * Xform::scaling(2.0, 4.0, 0.5);
+    let inv = m.inverse().expect("composite is invertible");
+    assert!((&m * &inv).is_identity(1e-5));
+    assert!((&inv * &m).is_identity(1e-5));
+
+    let p = Point::new(1.0, 2.0, 3.0);
+    let back = inv.transform_point(&m.transform_point(&p));
+    assert!((back.x - p.x).abs() < 1e-5 && (back.y - p.y).abs() < 1e-5 && (back.z - p.z).abs() < 1e-5);
+}
+
+#[test]
+fn test_inverse_of_singular_matrix_is_none() {
+    assert!(Xform::scaling(1.0, 0.0, 1.0).inverse().is_none());
+    assert!(Xform::new(0.0).inverse().is_none());
+    assert!(Xform::new(1.0).inverse().is_none());
+}
+
+#[test]
+fn test_determinant() {
+    assert_eq!(Xform::identity().determinant(), 1.0);
+    assert_eq!(Xform::scaling(2.0, 3.0, 4.0).determinant(), 24.0);
+    assert!((Xform::rotation_x(0.9).determinant() - 1.0).abs() < 1e-6);
+    assert_eq!(Xform::translation(5.0, 6.0, 7.0).determinant(), 1.0);
+    assert!(Xform::scaling(1.0, -1.0, 1.0).determinant() < 0.0);
+    assert_eq!(Xform::scaling(1.0, 0.0, 1.0).determinant(), 0.0);
+
+    let a = Xform::rotation_z(0.3) * Xform::scaling(2.0, 1.0, 3.0);
+    let b = Xform::translation(1.0, 2.0, 3.0) * Xform::scaling(0.5, 0.5, 0.5);
+    assert!(((&a * &b).determinant() - a.determinant() * b.determinant()).abs() < 1e-5);
+}
+
+#[test]
+fn test_transpose() {
+    let mut xform = Xform::identity();
+    xform[(0, 1)] = 7.0;
+    xform[(1, 0)] = -2.0;
+    xform[(2, 3)] = 5.0;
+    let t = xform.transpose();
+    assert_eq!(t[(1, 0)], 7.0);
+    assert_eq!(t[(0, 1)], -2.0);
+    assert_eq!(t[(3, 2)], 5.0);
+    assert_eq!(t[(2, 3)], 0.0);
+    assert!(t.transpose().is_close(&xform, 0.0));
+}
+
+#[test]
+fn test_transform_vector_ignores_translation() {
+    let v = Vector::new(1.0, -2.0, 3.0);
+    let moved = Xform::translation(10.0, 20.0, 30.0).transform_vector(&v);
+    assert_eq!((moved.x, moved.y, moved.z), (1.0, -2.0, 3.0));
+
+    let doubled = Xform::scaling(2.0, 2.0, 2.0).transform_vector(&v);
+    assert_eq!((doubled.x, doubled.y, doubled.z), (2.0, -4.0, 6.0));
+}
+
+#[test]
+fn test_transform_normal_stays_perpendicular_under_non_uniform_scaling() {
+    let xform = Xform::translation(1.0, 2.0, 3.0) * Xform::scaling(3.0, 1.0, 0.5);
+    // A tangent and normal of the same plane
+    let tangent = Vector::new(1.0, -1.0, 0.0);
+    let normal = Vector::new(1.0, 1.0, 1.0);
+    let t = xform.transform_vector(&tangent);
+    let n = xform.transform_normal(&normal);
+    assert!(t.dot(&n).abs() < 1e-5);
+    // The plain vector transform would break perpendicularity
+    assert!(t.dot(&xform.transform_vector(&normal)).abs() > 1e-2);
+
+    let rotation = Xform::rotation_z(0.6);
+    let rn = rotation.transform_normal(&normal);
+    let rv = rotation.transform_vector(&normal);
+    assert!((rn.x - rv.x).abs() < 1e-5 && (rn.y - rv.y).abs() < 1e-5 && (rn.z - rv.z).abs() < 1e-5);
+}
+
+#[test]
+fn test_rotation_about_arbitrary_axis() {
+    let quarter = std::f32::consts::FRAC_PI_2;
+    let p = Xform::rotation(&Vector::new(0.0, 0.0, 1.0), quarter).transform_point(&Point::new(1.0, 0.0, 0.0));
+    assert!(p.x.abs() < 1e-6 && (p.y - 1.0).abs() < 1e-6 && p.z.abs() < 1e-6);
+
+    // Principal axes agree with the dedicated constructors, whatever the axis length
+    assert!(Xform::rotation(&Vector::new(3.0, 0.0, 0.0), 0.7).is_close(&Xform::rotation_x(0.7), 1e-6));
+    assert!(Xform::rotation(&Vector::new(0.0, 1.0, 0.0), 0.7).is_close(&Xform::rotation_y(0.7), 1e-6));
+    assert!(Xform::rotation(&Vector::new(0.0, 0.0, 0.5), 0.7).is_close(&Xform::rotation_z(0.7), 1e-6));
+
+    // A third of a turn about the diagonal cycles the axes
+    let diagonal = Vector::new(1.0, 1.0, 1.0);
+    let cycle = Xform::rotation(&diagonal, 2.0 * std::f32::consts::PI / 3.0);
+    let q = cycle.transform_point(&Point::new(1.0, 0.0, 0.0));
+    assert!(q.x.abs() < 1e-5 && (q.y - 1.0).abs() < 1e-5 && q.z.abs() < 1e-5);
+    assert!(cycle.is_rigid(1e-5));
+}
+
+#[test]
+fn test_principal_rotations_follow_right_hand_rule() {
+    let quarter = std::f32::consts::FRAC_PI_2;
+    let y = Xform::rotation_x(quarter).transform_point(&Point::new(0.0, 1.0, 0.0));
+    assert!(y.y.abs() < 1e-6 && (y.z - 1.0).abs() < 1e-6);
+    let z = Xform::rotation_y(quarter).transform_point(&Point::new(0.0, 0.0, 1.0));
+    assert!((z.x - 1.0).abs() < 1e-6 && z.z.abs() < 1e-6);
+    let x = Xform::rotation_z(quarter).transform_point(&Point::new(1.0, 0.0, 0.0));
+    assert!(x.x.abs() < 1e-6 && (x.y - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_decompose_recovers_translation_rotation_scale() {
+    let axis = Vector::new(1.0, -2.0, 0.5).normalize();
+    let rotation = Quaternion::from_axis_angle(axis, 1.3);
+    let xform = Xform::translation(4.0, -1.0, 2.5) * rotation.to_xform() * Xform::scaling(2.0, 0.5, 3.0);
+
+    let (t, r, s) = xform.decompose();
+    assert!((t.x - 4.0).abs() < 1e-5 && (t.y + 1.0).abs() < 1e-5 && (t.z - 2.5).abs() < 1e-5);
+    assert!((s.x - 2.0).abs() < 1e-5 && (s.y - 0.5).abs() < 1e-5 && (s.z - 3.0).abs() < 1e-5);
+    assert!((r.dot(&rotation).abs() - 1.0).abs() < 1e-5);
+
+    let rebuilt = Xform::translation(t.x, t.y, t.z) * r.to_xform() * Xform::scaling(s.x, s.y, s.z);
+    assert!(rebuilt.is_close(&xform, 1e-5));
+}
+
+#[test]
+fn test_decompose_mirror_flips_one_scale_axis() {
+    let xform = Xform::rotation_z(0.4) * Xform::scaling(1.0, -2.0, 1.0);
+    let (_, r, s) = xform.decompose();
+    assert!(s.x < 0.0 && s.y > 0.0 && s.z > 0.0);
+    let rebuilt = r.to_xform() * Xform::scaling(s.x, s.y, s.z);
+    assert!(rebuilt.is_close(&xform, 1e-5));
+    assert!(r.to_xform().is_rigid(1e-5));
+}
+
+#[test]
+fn test_look_at_maps_target_onto_negative_z() {
+    let up = Vector::new(0.0, 1.0, 0.0);
+    let view = Xform::look_at(&Point::new(0.0, 0.0, 5.0), &Point::new(0.0, 0.0, 0.0), &up);
+    let origin = view.transform_point(&Point::new(0.0, 0.0, 0.0));
+    assert!(origin.x.abs() < 1e-6 && origin.y.abs() < 1e-6 && (origin.z + 5.0).abs() < 1e-6);
+    let above = view.transform_point(&Point::new(0.0, 1.0, 5.0));
+    assert!(above.x.abs() < 1e-6 && (above.y - 1.0).abs() < 1e-6 && above.z.abs() < 1e-6);
+
+    // Off-axis camera with a tilted up vector
+    let eye = Point::new(3.0, -2.0, 4.0);
+    let target = Point::new(-1.0, 1.0, 0.5);
+    let view = Xform::look_at(&eye, &target, &Vector::new(0.3, 0.2, 1.0));
+    let t = view.transform_point(&target);
+    assert!(t.x.abs() < 1e-5 && t.y.abs() < 1e-5 && (t.z + eye.distance(&target)).abs() < 1e-5);
+    assert!(view.is_rigid(1e-5));
+}
+
+#[test]
+fn test_mirror_across_planes() {
+    let origin = Point::new(0.0, 0.0, 0.0);
+    let p = Point::new(1.0, 2.0, 3.0);
+
+    let xy = Xform::mirror(&origin, &Vector::new(0.0, 0.0, 1.0)).transform_point(&p);
+    assert!((xy.x - 1.0).abs() < 1e-6 && (xy.y - 2.0).abs() < 1e-6 && (xy.z + 3.0).abs() < 1e-6);
+
+    let yz = Xform::mirror(&origin, &Vector::new(1.0, 0.0, 0.0)).transform_point(&p);
+    assert!((yz.x + 1.0).abs() < 1e-6 && (yz.y - 2.0).abs() < 1e-6 && (yz.z - 3.0).abs() < 1e-6);
+
+    // Offset, tilted plane: reflecting twice is the identity and the determinant is -1
+    let mirror = Xform::mirror(&Point::new(1.0, -1.0, 2.0), &Vector::new(1.0, 2.0, -2.0));
+    assert!((&mirror * &mirror).is_identity(1e-5));
+    assert!((mirror.determinant() + 1.0).abs() < 1e-5);
+    assert!(Xform::mirror(&origin, &Vector::new(0.0, 0.0, 0.0)).is_identity(0.0));
+}
+
+#[test]
+fn test_normal_matrix_falls_back_for_singular_transforms() {
+    let flatten = Xform::scaling(1.0, 1.0, 0.0);
+    assert!(flatten.normal_matrix().is_close(&flatten, 0.0));
+    let scale = Xform::scaling(2.0, 4.0, 0.5);
+    assert!(scale.normal_matrix().is_close(&Xform::scaling(0.5, 0.25, 2.0), 1e-6));
+}